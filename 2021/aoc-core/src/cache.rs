@@ -0,0 +1,70 @@
+use crate::site::Site;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Puzzle inputs cached under `~/.cache/aoc/<year>/<day>.txt`, and fetched from the site with
+/// the account's session token the first time they're needed.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    dir: PathBuf,
+    site: Site,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>, site: Site) -> Self {
+        InputCache {
+            dir: dir.into(),
+            site,
+        }
+    }
+
+    /// The default cache in `~/.cache/aoc`, or `AOC_CACHE_DIR` if it's set, fetching from
+    /// [`Site::from_env`].
+    pub fn from_env() -> Self {
+        let dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| env::home_dir().unwrap_or_default().join(".cache/aoc"));
+        InputCache::new(dir, Site::from_env())
+    }
+
+    /// Where state for a year is kept, alongside its cached inputs.
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("{}.txt", day))
+    }
+
+    /// Path to the cached input, fetching it first if it isn't cached yet.
+    pub fn fetch_if_missing(&self, year: u16, day: u8) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+        if !path.exists() {
+            let input = self.fetch(year, day)?;
+            write(&path, &input)?;
+        }
+        Ok(path)
+    }
+
+    /// Reads the cached input, fetching it first if it isn't cached yet.
+    pub fn get(&self, year: u16, day: u8) -> io::Result<String> {
+        crate::input::read_to_string(self.fetch_if_missing(year, day)?)
+    }
+
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String> {
+        self.site.get(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+fn write(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
@@ -0,0 +1,339 @@
+//! Points and vectors on the integer lattice, rotations in 3D, and axis-aligned boxes.
+//!
+//! Points are positions and vectors are the differences between them, so subtracting two points
+//! gives a vector and a point can be moved by adding a vector, but points can't be added.
+
+use std::ops::{Add, AddAssign, Mul, Neg, RangeInclusive, Sub, SubAssign};
+
+macro_rules! lattice_types {
+    ($point:ident, $vec:ident, $n:literal, $($axis:ident),+) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $point {
+            $(pub $axis: isize,)+
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $vec {
+            $(pub $axis: isize,)+
+        }
+
+        impl $point {
+            pub const ORIGIN: $point = $point { $($axis: 0,)+ };
+
+            pub fn new($($axis: isize),+) -> Self {
+                $point { $($axis,)+ }
+            }
+
+            /// The vector from the origin to this point.
+            pub fn to_vec(self) -> $vec {
+                self - $point::ORIGIN
+            }
+
+            /// Distance moving along one axis at a time.
+            pub fn manhattan_distance(self, other: $point) -> usize {
+                (self - other).manhattan_len()
+            }
+
+            /// Distance moving along any number of axes at a time, like a king in chess.
+            pub fn chebyshev_distance(self, other: $point) -> usize {
+                (self - other).chebyshev_len()
+            }
+        }
+
+        impl $vec {
+            pub const ZERO: $vec = $vec { $($axis: 0,)+ };
+
+            pub fn new($($axis: isize),+) -> Self {
+                $vec { $($axis,)+ }
+            }
+
+            pub fn manhattan_len(self) -> usize {
+                0 $(+ self.$axis.unsigned_abs())+
+            }
+
+            pub fn chebyshev_len(self) -> usize {
+                [$(self.$axis.unsigned_abs()),+].into_iter().max().unwrap_or(0)
+            }
+        }
+
+        impl From<$point> for [isize; $n] {
+            fn from(p: $point) -> Self {
+                [$(p.$axis),+]
+            }
+        }
+
+        impl From<[isize; $n]> for $point {
+            fn from([$($axis),+]: [isize; $n]) -> Self {
+                $point { $($axis,)+ }
+            }
+        }
+
+        impl From<$vec> for [isize; $n] {
+            fn from(v: $vec) -> Self {
+                [$(v.$axis),+]
+            }
+        }
+
+        impl From<[isize; $n]> for $vec {
+            fn from([$($axis),+]: [isize; $n]) -> Self {
+                $vec { $($axis,)+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $vec;
+
+            fn sub(self, other: $point) -> $vec {
+                $vec { $($axis: self.$axis - other.$axis,)+ }
+            }
+        }
+
+        impl Add<$vec> for $point {
+            type Output = $point;
+
+            fn add(self, v: $vec) -> $point {
+                $point { $($axis: self.$axis + v.$axis,)+ }
+            }
+        }
+
+        impl Sub<$vec> for $point {
+            type Output = $point;
+
+            fn sub(self, v: $vec) -> $point {
+                $point { $($axis: self.$axis - v.$axis,)+ }
+            }
+        }
+
+        impl AddAssign<$vec> for $point {
+            fn add_assign(&mut self, v: $vec) {
+                *self = *self + v;
+            }
+        }
+
+        impl SubAssign<$vec> for $point {
+            fn sub_assign(&mut self, v: $vec) {
+                *self = *self - v;
+            }
+        }
+
+        impl Add for $vec {
+            type Output = $vec;
+
+            fn add(self, other: $vec) -> $vec {
+                $vec { $($axis: self.$axis + other.$axis,)+ }
+            }
+        }
+
+        impl Sub for $vec {
+            type Output = $vec;
+
+            fn sub(self, other: $vec) -> $vec {
+                $vec { $($axis: self.$axis - other.$axis,)+ }
+            }
+        }
+
+        impl AddAssign for $vec {
+            fn add_assign(&mut self, other: $vec) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $vec {
+            fn sub_assign(&mut self, other: $vec) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<isize> for $vec {
+            type Output = $vec;
+
+            fn mul(self, n: isize) -> $vec {
+                $vec { $($axis: self.$axis * n,)+ }
+            }
+        }
+
+        impl Neg for $vec {
+            type Output = $vec;
+
+            fn neg(self) -> $vec {
+                $vec { $($axis: -self.$axis,)+ }
+            }
+        }
+    };
+}
+
+lattice_types!(Point2, Vec2, 2, x, y);
+lattice_types!(Point3, Vec3, 3, x, y, z);
+
+/// A rotation in 3D by some multiple of 90° about each axis, as the matrix that applies it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[isize; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// The 24 ways to turn something to face along one of the six axis directions with one of
+    /// four sides up, starting with the identity. These are the signed permutation matrices
+    /// with determinant 1, leaving out the mirror images.
+    pub fn all() -> [Rotation; 24] {
+        const PERMUTATIONS: [([usize; 3], isize); 6] = [
+            ([0, 1, 2], 1),
+            ([0, 2, 1], -1),
+            ([1, 0, 2], -1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([2, 1, 0], -1),
+        ];
+
+        let mut rotations = [Rotation::IDENTITY; 24];
+        let mut i = 0;
+        for (axes, parity) in PERMUTATIONS {
+            for signs in 0..8 {
+                let sign = |row: usize| if signs >> row & 1 == 1 { -1 } else { 1 };
+                if parity * sign(0) * sign(1) * sign(2) != 1 {
+                    continue;
+                }
+                let mut matrix = [[0; 3]; 3];
+                for row in 0..3 {
+                    matrix[row][axes[row]] = sign(row);
+                }
+                rotations[i] = Rotation(matrix);
+                i += 1;
+            }
+        }
+        rotations
+    }
+
+    fn apply(&self, v: [isize; 3]) -> [isize; 3] {
+        self.0
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+    }
+}
+
+impl Mul<Vec3> for Rotation {
+    type Output = Vec3;
+
+    fn mul(self, v: Vec3) -> Vec3 {
+        self.apply(v.into()).into()
+    }
+}
+
+/// Rotates a point about the origin.
+impl Mul<Point3> for Rotation {
+    type Output = Point3;
+
+    fn mul(self, p: Point3) -> Point3 {
+        self.apply(p.into()).into()
+    }
+}
+
+/// An axis-aligned box of lattice points in `N` dimensions, including the points on its faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb<const N: usize> {
+    pub min: [isize; N],
+    pub max: [isize; N],
+}
+
+impl<const N: usize> Aabb<N> {
+    /// The box between two opposite corners, in either order.
+    pub fn new(a: impl Into<[isize; N]>, b: impl Into<[isize; N]>) -> Self {
+        let (a, b) = (a.into(), b.into());
+        Aabb {
+            min: std::array::from_fn(|i| a[i].min(b[i])),
+            max: std::array::from_fn(|i| a[i].max(b[i])),
+        }
+    }
+
+    /// The range of each axis the box covers.
+    pub fn ranges(&self) -> [RangeInclusive<isize>; N] {
+        std::array::from_fn(|i| self.min[i]..=self.max[i])
+    }
+
+    /// Number of lattice points in the box.
+    pub fn volume(&self) -> usize {
+        (0..N)
+            .map(|i| self.max[i].abs_diff(self.min[i]) + 1)
+            .product()
+    }
+
+    pub fn contains(&self, point: impl Into<[isize; N]>) -> bool {
+        let point = point.into();
+        (0..N).all(|i| (self.min[i]..=self.max[i]).contains(&point[i]))
+    }
+
+    /// Whether `other` is entirely inside this box.
+    pub fn contains_box(&self, other: &Aabb<N>) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The box where both boxes overlap, if they do.
+    pub fn intersection(&self, other: &Aabb<N>) -> Option<Aabb<N>> {
+        let min: [isize; N] = std::array::from_fn(|i| self.min[i].max(other.min[i]));
+        let max: [isize; N] = std::array::from_fn(|i| self.max[i].min(other.max[i]));
+        (0..N)
+            .all(|i| min[i] <= max[i])
+            .then_some(Aabb { min, max })
+    }
+}
+
+impl<const N: usize> From<[RangeInclusive<isize>; N]> for Aabb<N> {
+    fn from(ranges: [RangeInclusive<isize>; N]) -> Self {
+        Aabb::new(
+            ranges.each_ref().map(|r| *r.start()),
+            ranges.each_ref().map(|r| *r.end()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_operators() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a) * 2, Point2::new(7, -6));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(-Vec3::new(1, -2, 3), Vec3::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        // every rotation turns a point with distinct coordinates somewhere different
+        let p = Point3::new(1, 2, 3);
+        let turned: HashSet<Point3> = rotations.iter().map(|&r| r * p).collect();
+        assert_eq!(turned.len(), 24);
+
+        // and never mirrors it, which would swap the x, y and z axes' handedness
+        let (x, y) = (Vec3::new(1, 0, 0), Vec3::new(0, 1, 0));
+        for r in rotations {
+            let (rx, ry, rz) = (r * x, r * y, r * Vec3::new(0, 0, 1));
+            let cross = Vec3::new(
+                rx.y * ry.z - rx.z * ry.y,
+                rx.z * ry.x - rx.x * ry.z,
+                rx.x * ry.y - rx.y * ry.x,
+            );
+            assert_eq!(cross, rz);
+        }
+    }
+
+    #[test]
+    fn test_aabb() {
+        let a = Aabb::from([0..=3, 0..=3, 0..=3]);
+        let b = Aabb::new([2, 1, 3], [5, 2, 6]);
+        assert_eq!(a.volume(), 64);
+        assert_eq!(a.intersection(&b), Some(Aabb::from([2..=3, 1..=2, 3..=3])));
+        assert_eq!(a.intersection(&Aabb::new([4, 0, 0], [5, 5, 5])), None);
+        assert!(a.contains(Point3::new(3, 0, 2)));
+        assert!(!a.contains(Point3::new(3, 0, 4)));
+        assert!(a.contains_box(&Aabb::from([1..=2, 0..=3, 3..=3])));
+        assert!(!a.contains_box(&b));
+    }
+}
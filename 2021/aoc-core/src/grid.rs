@@ -0,0 +1,329 @@
+use crate::parse::{char_tokens, ParseError};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// Offsets to the cells above, below, left and right of a cell.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets to every cell around a cell, including diagonals.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A cell's position in a grid, counting rows down from the top and columns right from the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// Moves by a signed offset, or None if that would leave the top or left edge.
+    pub fn offset(self, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        Some(Pos::new(
+            self.row.checked_add_signed(d_row)?,
+            self.col.checked_add_signed(d_col)?,
+        ))
+    }
+
+    /// The position as signed (row, col), to step outside the grid with.
+    pub fn signed(self) -> (isize, isize) {
+        let to_isize = |n: usize| isize::try_from(n).expect("position too big for isize");
+        (to_isize(self.row), to_isize(self.col))
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos::new(row, col)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Builds a grid from the value for each position.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| Pos::new(row, col)))
+            .map(&mut f)
+            .collect();
+        Grid { cells, rows, cols }
+    }
+
+    /// Parses a map with one char per cell, e.g. a line of digits for each row.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(&str) -> crate::Result<T>,
+    ) -> crate::Result<Self> {
+        let mut cells = vec![];
+        let mut rows = 0;
+        let mut cols = None;
+        for line in input.lines() {
+            let len_before = cells.len();
+            for c in char_tokens(line) {
+                cells.push(parse_cell(c)?);
+            }
+            let row_len = cells.len() - len_before;
+            match cols {
+                None => cols = Some(row_len),
+                Some(cols) if cols != row_len => {
+                    let message = format!("expected {} cells in row, found {}", cols, row_len);
+                    return Err(ParseError::new(line, message));
+                }
+                Some(_) => {}
+            }
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Grid { cells, rows, cols }),
+            _ => Err(ParseError::missing(input, "a grid")),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.cols + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.cols + pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// Looks up a signed position, which may be off any edge of the grid.
+    pub fn get_signed(&self, (row, col): (isize, isize)) -> Option<&T> {
+        let pos = Pos::new(usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        self.get(pos)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Pos::new(row, col)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols)
+    }
+
+    /// Moves by a signed offset, or None if that would leave the grid.
+    pub fn offset(&self, pos: Pos, d: (isize, isize)) -> Option<Pos> {
+        pos.offset(d).filter(|&p| self.contains(p))
+    }
+
+    /// Moves by a signed offset, wrapping around to the opposite edge when leaving the grid.
+    pub fn wrapping_offset(&self, pos: Pos, (d_row, d_col): (isize, isize)) -> Pos {
+        let (row, col) = pos.signed();
+        let (rows, cols) = Pos::new(self.rows, self.cols).signed();
+        Pos::new(
+            (row + d_row).rem_euclid(rows) as usize,
+            (col + d_col).rem_euclid(cols) as usize,
+        )
+    }
+
+    /// Positions above, below, left and right of `pos` that are within the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let (rows, cols) = (self.rows, self.cols);
+        NEIGHBORS4
+            .into_iter()
+            .filter_map(move |d| pos.offset(d))
+            .filter(move |p| p.row < rows && p.col < cols)
+    }
+
+    /// Positions all around `pos`, including diagonals, that are within the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let (rows, cols) = (self.rows, self.cols);
+        NEIGHBORS8
+            .into_iter()
+            .filter_map(move |d| pos.offset(d))
+            .filter(move |p| p.row < rows && p.col < cols)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+/// Draws each cell with its `Display`, which should be a single char to get the map back.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A grid in the middle of an infinite plane of `background` cells, e.g. an image that grows
+/// each time it's processed. Signed positions are relative to the grid's top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfiniteGrid<T> {
+    pub grid: Grid<T>,
+    pub background: T,
+}
+
+impl<T> InfiniteGrid<T> {
+    pub fn new(grid: Grid<T>, background: T) -> Self {
+        InfiniteGrid { grid, background }
+    }
+
+    pub fn get(&self, pos: (isize, isize)) -> &T {
+        self.grid.get_signed(pos).unwrap_or(&self.background)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u8> {
+        Grid::parse(input, crate::parse::parse_token).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Pos::new(1, 0)], 4);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let input = "123\n45\n";
+        let e = Grid::<u8>::parse(input, crate::parse::parse_token).unwrap_err();
+        assert_eq!(
+            e.locate(input).to_string(),
+            "line 2, column 1: expected 3 cells in row, found 2 `45`"
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits("123\n456\n789");
+        let corner: Vec<Pos> = grid.neighbors4(Pos::new(0, 0)).collect();
+        assert_eq!(corner, [Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Pos::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_wrapping_offset() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.wrapping_offset(Pos::new(0, 2), (0, 1)), Pos::new(0, 0));
+        assert_eq!(
+            grid.wrapping_offset(Pos::new(0, 0), (-1, -1)),
+            Pos::new(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_infinite_background() {
+        let grid = InfiniteGrid::new(digits("12\n34"), 0);
+        assert_eq!(*grid.get((1, 1)), 4);
+        assert_eq!(*grid.get((-1, 0)), 0);
+        assert_eq!(*grid.get((0, 2)), 0);
+    }
+}
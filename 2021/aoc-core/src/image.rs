@@ -0,0 +1,171 @@
+//! Writing grids out as images, for states too big to make sense of in a terminal.
+//!
+//! Each cell becomes one pixel, colored by a function of the cell. PPM files can always be
+//! written, PNG needs the `png` feature.
+
+use crate::grid::Grid;
+use crate::{AnySolution, Part, Solution};
+use std::any::Any;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    pub fn gray(level: u8) -> Rgb {
+        Rgb(level, level, level)
+    }
+
+    /// The color `t` of the way from this color to `other`, with `t` from 0 to 1.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    /// One of a handful of colors that are easy to tell apart, for the `i`th of many things.
+    pub fn distinct(i: usize) -> Rgb {
+        const PALETTE: [Rgb; 8] = [
+            Rgb(230, 25, 75),
+            Rgb(60, 180, 75),
+            Rgb(255, 225, 25),
+            Rgb(0, 130, 200),
+            Rgb(245, 130, 48),
+            Rgb(145, 30, 180),
+            Rgb(70, 240, 240),
+            Rgb(240, 50, 230),
+        ];
+        PALETTE[i % PALETTE.len()]
+    }
+}
+
+fn pixels<T>(grid: &Grid<T>, mut color: impl FnMut(&T) -> Rgb) -> Vec<u8> {
+    grid.values()
+        .flat_map(|cell| {
+            let Rgb(r, g, b) = color(cell);
+            [r, g, b]
+        })
+        .collect()
+}
+
+/// Writes a binary PPM (P6) image.
+pub fn write_ppm<T>(
+    out: &mut impl Write,
+    grid: &Grid<T>,
+    color: impl FnMut(&T) -> Rgb,
+) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", grid.cols(), grid.rows())?;
+    out.write_all(&pixels(grid, color))
+}
+
+/// Writes an 8 bit RGB PNG image.
+#[cfg(feature = "png")]
+pub fn write_png<T>(
+    out: &mut impl Write,
+    grid: &Grid<T>,
+    color: impl FnMut(&T) -> Rgb,
+) -> io::Result<()> {
+    let to_u32 = |n: usize| u32::try_from(n).map_err(|_| io::Error::other("image too big for PNG"));
+    let mut encoder = png::Encoder::new(out, to_u32(grid.cols())?, to_u32(grid.rows())?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&pixels(grid, color))
+        .map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+/// Writes an image in the format the path's extension asks for, `.ppm` or `.png`.
+pub fn save<T>(path: &Path, grid: &Grid<T>, color: impl FnMut(&T) -> Rgb) -> io::Result<()> {
+    let with_path = |e: io::Error| io::Error::new(e.kind(), format!("{}: {}", path.display(), e));
+    let is_png = match path.extension().and_then(|e| e.to_str()) {
+        Some("ppm") => false,
+        Some("png") => true,
+        _ => {
+            let e = io::Error::new(io::ErrorKind::InvalidInput, "expected a .ppm or .png file");
+            return Err(with_path(e));
+        }
+    };
+    if is_png && !cfg!(feature = "png") {
+        let e = io::Error::other("writing PNGs needs the png feature");
+        return Err(with_path(e));
+    }
+
+    let mut out = BufWriter::new(File::create(path).map_err(with_path)?);
+    #[cfg(feature = "png")]
+    if is_png {
+        return write_png(&mut out, grid, color)
+            .and_then(|_| out.flush())
+            .map_err(with_path);
+    }
+    write_ppm(&mut out, grid, color)
+        .and_then(|_| out.flush())
+        .map_err(with_path)
+}
+
+/// A day whose state can be saved as an image, alongside solving it.
+pub trait ExportImage: Solution {
+    /// Saves the state that answers `part` to `path`, using [`save`].
+    fn export_image(&self, part: Part, input: &Self::Input, path: &Path) -> io::Result<()>;
+}
+
+/// Object safe version of [`ExportImage`], like [`AnySolution`] is for [`Solution`].
+pub trait AnyExportImage: AnySolution {
+    fn export_image_any(&self, part: Part, input: &dyn Any, path: &Path) -> io::Result<()>;
+}
+
+impl<E> AnyExportImage for E
+where
+    E: ExportImage + Sync,
+    E::Input: 'static,
+{
+    fn export_image_any(&self, part: Part, input: &dyn Any, path: &Path) -> io::Result<()> {
+        let input = input
+            .downcast_ref::<E::Input>()
+            .expect("input was parsed by a different solution");
+        self.export_image(part, input, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_ppm() {
+        let grid = Grid::from_fn(1, 2, |pos| pos.col == 1);
+        let mut out = vec![];
+        write_ppm(
+            &mut out,
+            &grid,
+            |&lit| if lit { Rgb::WHITE } else { Rgb(1, 2, 3) },
+        )
+        .unwrap();
+        assert_eq!(out, b"P6\n2 1\n255\n\x01\x02\x03\xff\xff\xff");
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(Rgb::BLACK.mix(Rgb(100, 200, 50), 0.5), Rgb(50, 100, 25));
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 2.0), Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_save_unknown_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let grid = Grid::new(1, 1, 0);
+        let e = save(&dir.path().join("out.bmp"), &grid, |_| Rgb::BLACK).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::cache::InputCache;
+use crate::parse::{parse_token, ParseError};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Attach the filename to IO errors, since "No such file or directory" on its own doesn't say much.
+fn with_filename(path: &Path, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+/// Reads the whole file into a string.
+pub fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| with_filename(path, e))
+}
+
+/// Location of a named input file (e.g. "test" or "input") in a day's `input/` dir.
+pub fn day_input_path(day: u8, name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("aoc{:02}", day))
+        .join("input")
+        .join(format!("{}.txt", name))
+}
+
+/// Reads a named input file from a day's `input/` dir, with its line endings normalized.
+pub fn read_day_input(day: u8, name: &str) -> io::Result<String> {
+    read_to_string(day_input_path(day, name)).map(normalize_newlines)
+}
+
+/// Converts `\r\n` line endings to `\n` and drops trailing newlines, so the last line of the
+/// input is never followed by an empty one.
+pub fn normalize_newlines(input: String) -> String {
+    let input = if input.contains('\r') {
+        input.replace("\r\n", "\n")
+    } else {
+        input
+    };
+    let len = input.trim_end_matches('\n').len();
+    let mut input = input;
+    input.truncate(len);
+    input
+}
+
+/// Finds a named input for a day. The puzzle input ("input") is read from the day's `input/` dir
+/// when it's been committed there, and from the cache otherwise, which fetches it if it's missing.
+pub fn find_input(cache: &InputCache, year: u16, day: u8, name: &str) -> io::Result<PathBuf> {
+    let path = day_input_path(day, name);
+    if name == "input" && !path.exists() {
+        cache.fetch_if_missing(year, day)
+    } else {
+        Ok(path)
+    }
+}
+
+/// Where to read a day's input from, as given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `-`
+    Stdin,
+    /// Anything with a `/` or an extension, e.g. `../other/input.txt`
+    Path(PathBuf),
+    /// A file in the day's `input/` dir, e.g. `test` for `input/test.txt`
+    Named(String),
+}
+
+impl InputSource {
+    /// Reads the input with its line endings normalized, along with where it was read from
+    /// for error messages. Named inputs are found with [`find_input`].
+    pub fn read(&self, cache: &InputCache, year: u16, day: u8) -> io::Result<(PathBuf, String)> {
+        let (path, input) = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| io::Error::new(e.kind(), format!("stdin: {}", e)))?;
+                (PathBuf::from("<stdin>"), input)
+            }
+            InputSource::Path(path) => (path.clone(), read_to_string(path)?),
+            InputSource::Named(name) => {
+                let path = find_input(cache, year, day, name)?;
+                let input = read_to_string(&path)?;
+                (path, input)
+            }
+        };
+        Ok((path, normalize_newlines(input)))
+    }
+
+    /// Opens the input to be read a line at a time, for inputs too big to read all at once.
+    /// Unlike [`InputSource::read`], line endings are left as they are.
+    pub fn open(
+        &self,
+        cache: &InputCache,
+        year: u16,
+        day: u8,
+    ) -> io::Result<(PathBuf, Box<dyn BufRead>)> {
+        let path = match self {
+            InputSource::Stdin => {
+                return Ok((PathBuf::from("<stdin>"), Box::new(io::stdin().lock())))
+            }
+            InputSource::Path(path) => path.clone(),
+            InputSource::Named(name) => find_input(cache, year, day, name)?,
+        };
+        let file = File::open(&path).map_err(|e| with_filename(&path, e))?;
+        Ok((path, Box::new(BufReader::new(file))))
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err("input can't be empty".to_string())
+        } else if s == "-" {
+            Ok(InputSource::Stdin)
+        } else if s.contains(std::path::is_separator) || Path::new(s).extension().is_some() {
+            Ok(InputSource::Path(PathBuf::from(s)))
+        } else {
+            Ok(InputSource::Named(s.to_string()))
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "-"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Parses the first line of the input as a comma-separated list of values.
+pub fn parse_comma_separated<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|n| parse_token(n.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_newlines() {
+        assert_eq!(normalize_newlines("1\r\n2\r\n\r\n".to_string()), "1\n2");
+        assert_eq!(normalize_newlines("1\n\n2\n".to_string()), "1\n\n2");
+        assert_eq!(normalize_newlines("1".to_string()), "1");
+    }
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!("test".parse(), Ok(InputSource::Named("test".to_string())));
+        assert_eq!(
+            "input.txt".parse(),
+            Ok(InputSource::Path(PathBuf::from("input.txt")))
+        );
+        assert_eq!(
+            "../aoc01/input/test".parse(),
+            Ok(InputSource::Path(PathBuf::from("../aoc01/input/test")))
+        );
+        assert!("".parse::<InputSource>().is_err());
+    }
+}
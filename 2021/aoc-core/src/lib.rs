@@ -0,0 +1,18 @@
+//! Helpers shared by every 2021 Advent of Code day.
+
+pub mod cache;
+pub mod geom;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod parse;
+pub mod search;
+pub mod site;
+mod solution;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod trace;
+pub mod viz;
+
+pub use parse::ParseError;
+pub use solution::{run_day, AnySolution, Part, Result, Solution};
@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// A malformed input, pointing at the token that couldn't be parsed.
+///
+/// Parsers build errors from a slice of the input they were given, and the slice's address is
+/// used to find its line and column once the error makes it back to [`ParseError::locate`]. The
+/// runner does that for every day, and attaches the file name with [`ParseError::with_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    token: String,
+    addr: usize,
+    file: Option<PathBuf>,
+    position: Option<(usize, usize)>,
+}
+
+impl ParseError {
+    /// An error about `token`, which should be a slice of the input being parsed.
+    pub fn new(token: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            token: token.to_string(),
+            addr: token.as_ptr() as usize,
+            file: None,
+            position: None,
+        }
+    }
+
+    /// An error for something missing after the end of `s`, e.g. a line that stops too early.
+    pub fn missing(s: &str, what: &str) -> Self {
+        ParseError::new(&s[s.len()..], format!("expected {}", what))
+    }
+
+    /// Finds the line and column of the token within the input it came from. Errors about
+    /// tokens that aren't part of `input` are left without a position.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.position.is_none() && (start..=start + input.len()).contains(&self.addr) {
+            let before = &input[..self.addr - start];
+            let line = before.matches('\n').count() + 1;
+            let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+            self.position = Some((line, column));
+        }
+        self
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// 1-based line of the token, if it has been located.
+    pub fn line(&self) -> Option<usize> {
+        self.position.map(|(line, _)| line)
+    }
+
+    /// 1-based column of the token, if it has been located.
+    pub fn column(&self) -> Option<usize> {
+        self.position.map(|(_, column)| column)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.position) {
+            (Some(file), Some((line, column))) => {
+                write!(f, "{}:{}:{}: ", file.display(), line, column)?
+            }
+            (Some(file), None) => write!(f, "{}: ", file.display())?,
+            (None, Some((line, column))) => write!(f, "line {}, column {}: ", line, column)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " `{}`", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a token with its `FromStr` impl, e.g. a number.
+pub fn parse_token<T>(token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e: T::Err| ParseError::new(token, e.to_string()))
+}
+
+/// Splits `s` around the first `delimiter`, or errors if it isn't there.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(s, format!("expected `{}` in", delimiter)))
+}
+
+/// Strips `prefix` off the start of `s`, or errors if it isn't there.
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        if s.is_empty() {
+            ParseError::missing(s, &format!("`{}`", prefix))
+        } else {
+            ParseError::new(s, format!("expected `{}` at the start of", prefix))
+        }
+    })
+}
+
+/// Each char of `s` as its own slice, so errors about a single char can be located.
+pub fn char_tokens(s: &str) -> impl Iterator<Item = &str> {
+    s.char_indices().map(move |(i, c)| &s[i..i + c.len_utf8()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "1,2\n3,x,5\n";
+        let token = input.lines().nth(1).unwrap().split(',').nth(1).unwrap();
+        let e = parse_token::<u32>(token).unwrap_err().locate(input);
+        assert_eq!((e.line(), e.column(), e.token()), (Some(2), Some(3), "x"));
+        assert_eq!(
+            e.with_file("test.txt").to_string(),
+            "test.txt:2:3: invalid digit found in string `x`"
+        );
+    }
+
+    #[test]
+    fn test_locate_missing() {
+        let input = "a b\nc";
+        let e = ParseError::missing(input.lines().last().unwrap(), "a second word");
+        assert_eq!(
+            e.locate(input).to_string(),
+            "line 2, column 2: expected a second word"
+        );
+    }
+
+    #[test]
+    fn test_locate_other_input() {
+        let other = String::from("x");
+        let e = ParseError::new(&other, "unknown").locate("abc");
+        assert_eq!((e.line(), e.to_string()), (None, "unknown `x`".to_string()));
+    }
+}
@@ -0,0 +1,479 @@
+//! Shortest path searches over graphs given by a neighbors function, so the graph never has to
+//! be built up front.
+//!
+//! Each search starts from one node and runs until `stop` returns true for a node it reaches, or
+//! until every reachable node has been reached if it never does. Pass `|_| false` to get the
+//! distance to every node, or check for the target to only search as far as it.
+//!
+//! Searches remember the node(s) each node was reached from at its shortest distance, which is
+//! enough to rebuild the shortest paths once the search is over.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found: the distance to every node it reached, and the node it stopped at.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, usize>,
+    // every node a node can be reached from at its shortest distance, the start has none
+    predecessors: HashMap<N, Vec<N>>,
+    target: Option<N>,
+}
+
+impl<N: Hash + Eq + Clone> Search<N> {
+    fn new(start: N) -> Self {
+        Search {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+            target: None,
+        }
+    }
+
+    /// The node `stop` returned true for, if any.
+    pub fn target(&self) -> Option<&N> {
+        self.target.as_ref()
+    }
+
+    /// Distance to the node the search stopped at.
+    pub fn cost(&self) -> Option<usize> {
+        self.target.as_ref().map(|target| self.distances[target])
+    }
+
+    /// Distance to a node. If the search stopped early, nodes further away than the target may
+    /// only have the best distance found so far.
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<N, usize> {
+        self.distances
+    }
+
+    /// A shortest path from the start to the node the search stopped at, including both.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.target.as_ref()?)
+    }
+
+    /// A shortest path from the start to a node the search reached, including both.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors.get(path.last()?).and_then(|p| p.first()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from the start to the node the search stopped at.
+    pub fn all_paths(&self) -> Vec<Vec<N>> {
+        self.target
+            .as_ref()
+            .map_or_else(Vec::new, |target| self.all_paths_to(target))
+    }
+
+    /// Every shortest path from the start to a node the search reached. Paths that only differ
+    /// by edges that cost nothing aren't told apart, since those edges could go round in circles.
+    pub fn all_paths_to(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return vec![];
+        }
+        match self.predecessors.get(node) {
+            None => vec![vec![node.clone()]],
+            Some(predecessors) => predecessors
+                .iter()
+                .flat_map(|prev| self.all_paths_to(prev))
+                .map(|mut path| {
+                    path.push(node.clone());
+                    path
+                })
+                .collect(),
+        }
+    }
+
+    // Records the distance to `node` through `from` if it's the best yet, returning whether it
+    // was better. Ties are remembered too, so every shortest path can be rebuilt.
+    fn relax(&mut self, from: &N, node: &N, edge_cost: usize, distance: usize) -> bool {
+        match self.distances.get(node) {
+            Some(&best) if best < distance => false,
+            Some(&best) if best == distance => {
+                if let Some(predecessors) = self.predecessors.get_mut(node) {
+                    if edge_cost > 0 && !predecessors.contains(from) {
+                        predecessors.push(from.clone());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(node.clone(), distance);
+                self.predecessors.insert(node.clone(), vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    fn is_stale(&self, node: &N, distance: usize) -> bool {
+        distance > self.distances[node]
+    }
+}
+
+struct NodeCost<N> {
+    // what the queue is ordered by, the cost so far plus any heuristic
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+// The priority queue depends on `Ord`.
+// Explicitly implement the trait so the queue becomes a min-heap
+// instead of a max-heap. Nodes are left out so they don't need to be `Ord`.
+impl<N> Ord for NodeCost<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N> PartialOrd for NodeCost<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> PartialEq for NodeCost<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for NodeCost<N> {}
+
+/// Dijkstra's algorithm, for graphs with non-negative edge costs.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    stop: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, neighbors, |_| 0, stop)
+}
+
+/// A*, which is Dijkstra's algorithm looking at the nodes closest to the target first. The
+/// heuristic estimates the cost from a node to the target, and must never overestimate it or
+/// the cost found may not be the lowest.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut stop: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut unvisited_vertex_heap = BinaryHeap::new();
+    unvisited_vertex_heap.push(NodeCost {
+        priority: heuristic(&start),
+        cost: 0,
+        node: start,
+    });
+
+    // Examine the frontier with lower cost nodes first (min-heap)
+    while let Some(NodeCost { cost, node, .. }) = unvisited_vertex_heap.pop() {
+        // Important as we may have already found a better way
+        if search.is_stale(&node, cost) {
+            continue;
+        }
+
+        if stop(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        // For each node we can reach, see if we can find a way with
+        // a lower cost going through this node
+        for (neighbor, cost_to_neighbor) in neighbors(&node) {
+            let next_cost = cost + cost_to_neighbor;
+            if search.relax(&node, &neighbor, cost_to_neighbor, next_cost) {
+                unvisited_vertex_heap.push(NodeCost {
+                    priority: next_cost + heuristic(&neighbor),
+                    cost: next_cost,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// Breadth-first search, for graphs where every edge costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut stop: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if stop(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        for neighbor in neighbors(&node) {
+            if search.relax(&node, &neighbor, 1, distance + 1) {
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// 0-1 BFS, for graphs where every edge costs 0 or 1. Cheaper than Dijkstra's algorithm since
+/// a deque does the job of the priority queue.
+pub fn bfs01<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut stop: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut deque = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = deque.pop_front() {
+        if search.is_stale(&node, distance) {
+            continue;
+        }
+
+        if stop(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        for (neighbor, cost) in neighbors(&node) {
+            assert!(cost <= 1, "0-1 BFS edges must cost 0 or 1, not {}", cost);
+            if search.relax(&node, &neighbor, cost, distance + cost) {
+                // free edges go to the front so the deque stays sorted by distance
+                if cost == 0 {
+                    deque.push_front((neighbor, distance));
+                } else {
+                    deque.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+    }
+
+    search
+}
+
+/// Distance from `start` to `target` where every edge costs 1, searching forwards from the
+/// start and backwards from the target until they meet. This reaches far fewer nodes than
+/// [`bfs`] when the graph branches a lot. `reverse_neighbors` gives the nodes with an edge to a
+/// node, which is just `neighbors` for undirected graphs.
+pub fn bidirectional_bfs<N, I, J>(
+    start: N,
+    target: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut reverse_neighbors: impl FnMut(&N) -> J,
+) -> Option<usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+    J: IntoIterator<Item = N>,
+{
+    if start == target {
+        return Some(0);
+    }
+
+    let mut forwards = Frontier::new(start);
+    let mut backwards = Frontier::new(target);
+
+    while !forwards.layer.is_empty() && !backwards.layer.is_empty() {
+        // grow whichever side has less to look at
+        let meeting = if forwards.layer.len() <= backwards.layer.len() {
+            forwards.expand(&mut neighbors, &backwards)
+        } else {
+            backwards.expand(&mut reverse_neighbors, &forwards)
+        };
+        if meeting.is_some() {
+            return meeting;
+        }
+    }
+
+    None
+}
+
+// One side of a bidirectional search
+struct Frontier<N> {
+    distances: HashMap<N, usize>,
+    layer: Vec<N>,
+    depth: usize,
+}
+
+impl<N: Hash + Eq + Clone> Frontier<N> {
+    fn new(start: N) -> Self {
+        Frontier {
+            distances: HashMap::from([(start.clone(), 0)]),
+            layer: vec![start],
+            depth: 0,
+        }
+    }
+
+    // Reaches the next layer of nodes, returning the shortest distance through any that the
+    // other side has already reached. The whole layer is expanded before returning, since the
+    // first meeting found isn't necessarily the shortest.
+    fn expand<I: IntoIterator<Item = N>>(
+        &mut self,
+        neighbors: &mut impl FnMut(&N) -> I,
+        other: &Frontier<N>,
+    ) -> Option<usize> {
+        self.depth += 1;
+        let mut next_layer = vec![];
+        let mut seen_this_layer = HashSet::new();
+        let mut meeting = None;
+
+        for node in &self.layer {
+            for neighbor in neighbors(node) {
+                if self.distances.contains_key(&neighbor)
+                    || !seen_this_layer.insert(neighbor.clone())
+                {
+                    continue;
+                }
+                if let Some(other_distance) = other.distances.get(&neighbor) {
+                    let distance = self.depth + other_distance;
+                    meeting = Some(meeting.map_or(distance, |best: usize| best.min(distance)));
+                }
+                next_layer.push(neighbor);
+            }
+        }
+
+        for node in &next_layer {
+            self.distances.insert(node.clone(), self.depth);
+        }
+        self.layer = next_layer;
+        meeting
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3, with a shortcut 0 -5-> 3 and a dead end 4 -1-> 0
+    fn weighted(node: &u32) -> Vec<(u32, usize)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            4 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    // Integers where each one leads to double and one more than it
+    fn doubling(n: &u32) -> Vec<u32> {
+        vec![n * 2, n + 1]
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, weighted, |&n| n == 3);
+        assert_eq!((search.target(), search.cost()), (Some(&3), Some(3)));
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3]));
+
+        let all = dijkstra(0, weighted, |_| false);
+        assert_eq!(all.target(), None);
+        assert_eq!(
+            all.into_distances(),
+            HashMap::from([(0, 0), (1, 1), (2, 2), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn test_all_paths() {
+        // two ways round a square from 0 to 3, and a longer way through 4
+        let square = |&n: &u32| match n {
+            0 => vec![(1, 1), (2, 1), (4, 2)],
+            1 | 2 => vec![(3, 1)],
+            4 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(0, square, |&n| n == 3);
+        let mut paths = search.all_paths();
+        paths.sort();
+        assert_eq!(paths, [[0, 1, 3], [0, 2, 3]]);
+        assert_eq!(search.all_paths_to(&0), [[0]]);
+        assert!(search.all_paths_to(&5).is_empty());
+
+        // doubling and adding one both take 1 to 2, but that's still the one path
+        let search = bfs(1, doubling, |&n| n == 4);
+        assert_eq!(search.all_paths(), [[1, 2, 4]]);
+    }
+
+    #[test]
+    fn test_astar() {
+        let search = astar(
+            1,
+            |n| doubling(n).into_iter().map(|m| (m, 1)),
+            |&n| usize::from(n < 10),
+            |&n| n == 10,
+        );
+        assert_eq!(search.cost(), Some(4));
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(1, doubling, |&n| n == 10);
+        assert_eq!(search.cost(), Some(4));
+        assert_eq!(search.distance(&8), Some(3));
+    }
+
+    #[test]
+    fn test_bfs01() {
+        // doubling is free, adding one isn't
+        let neighbors = |&n: &u32| {
+            [(n * 2, 0), (n + 1, 1)]
+                .into_iter()
+                .filter(|&(m, _)| m <= 64)
+        };
+        let search = bfs01(1, neighbors, |&n| n == 40);
+        assert_eq!(search.cost(), Some(1));
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let halving = |&n: &u32| {
+            let mut from = vec![];
+            if n % 2 == 0 {
+                from.push(n / 2);
+            }
+            if n > 0 {
+                from.push(n - 1);
+            }
+            from
+        };
+        assert_eq!(bidirectional_bfs(1, 10, doubling, halving), Some(4));
+        assert_eq!(bidirectional_bfs(7, 7, doubling, halving), Some(0));
+        assert_eq!(bidirectional_bfs(5, 2, doubling, halving), None);
+    }
+}
@@ -0,0 +1,65 @@
+use std::env;
+use std::fs;
+use std::io;
+
+const SITE_URL: &str = "https://adventofcode.com";
+
+/// Client for the Advent of Code site, logged in with the account's session token.
+#[derive(Debug, Clone)]
+pub struct Site {
+    url: String,
+    session: Option<String>,
+}
+
+impl Site {
+    pub fn new(url: impl Into<String>, session: Option<String>) -> Self {
+        Site {
+            url: url.into(),
+            session,
+        }
+    }
+
+    /// The real site, unless `AOC_URL` points somewhere else (e.g. a stub server). The session
+    /// token is read from `AOC_SESSION`, falling back to `~/.config/aoc/session`.
+    pub fn from_env() -> Self {
+        let url = env::var("AOC_URL").unwrap_or_else(|_| SITE_URL.to_string());
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| {
+                let home = env::home_dir()?;
+                fs::read_to_string(home.join(".config/aoc/session")).ok()
+            })
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        Site::new(url, session)
+    }
+
+    /// GETs a page, e.g. `/2021/day/1/input`.
+    pub fn get(&self, path: &str) -> io::Result<String> {
+        let request = self.request("GET", path)?;
+        read_response(request.call())
+    }
+
+    /// POSTs a form to a page, e.g. an answer to `/2021/day/1/answer`.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> io::Result<String> {
+        let request = self.request("POST", path)?;
+        read_response(request.send_form(form))
+    }
+
+    fn request(&self, method: &str, path: &str) -> io::Result<ureq::Request> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "no session token, set AOC_SESSION or write it to ~/.config/aoc/session",
+            )
+        })?;
+        Ok(ureq::request(method, &format!("{}{}", self.url, path))
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "github.com/ddowl/advent-of-code"))
+    }
+}
+
+// ureq's errors already say which URL they were for
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> io::Result<String> {
+    response.map_err(io::Error::other)?.into_string()
+}
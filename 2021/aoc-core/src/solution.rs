@@ -0,0 +1,139 @@
+use crate::cache::InputCache;
+use crate::input::InputSource;
+use crate::parse::ParseError;
+use std::any::Any;
+use std::fmt::{Debug, Display};
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed input.
+pub trait Solution {
+    type Input: Debug;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> impl Display;
+    fn part2(&self, input: &Self::Input) -> impl Display;
+}
+
+/// Object safe version of [`Solution`] so the runner can keep every day in one list.
+/// Implemented for every `Solution`, the parsed input is passed around as `dyn Any`.
+pub trait AnySolution: Sync {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn solve_any(&self, part: Part, input: &dyn Any) -> String;
+    /// The parsed input's `Debug` output, for checking what the parser made of a file.
+    fn debug_any(&self, input: &dyn Any) -> String;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        let _span = tracing::info_span!("parse").entered();
+        match self.parse(input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.locate(input)),
+        }
+    }
+
+    fn solve_any(&self, part: Part, input: &dyn Any) -> String {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solution");
+        match part {
+            Part::One => {
+                let _span = tracing::info_span!("part1").entered();
+                self.part1(input).to_string()
+            }
+            Part::Two => {
+                let _span = tracing::info_span!("part2").entered();
+                self.part2(input).to_string()
+            }
+        }
+    }
+
+    fn debug_any(&self, input: &dyn Any) -> String {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solution");
+        format!("{:#?}", input)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(n: u8) -> std::result::Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(format!("invalid part: {}", n)),
+        }
+    }
+}
+
+/// Runs both parts against the day's input. Used by each day's own binary, which takes the
+/// input to read as its only argument, in the same forms as the runner's `--input`: a name in
+/// the day's `input/` dir (`input` if it's left out), a path, or `-` for stdin.
+pub fn run_day(day: u8, solution: &dyn AnySolution) {
+    crate::trace::init();
+    let _span = tracing::info_span!("day", year = 2021, day).entered();
+    let source: InputSource = match std::env::args()
+        .nth(1)
+        .as_deref()
+        .unwrap_or("input")
+        .parse()
+    {
+        Ok(source) => source,
+        Err(e) => exit_with_error(e),
+    };
+    let (path, input) = match source.read(&InputCache::from_env(), 2021, day) {
+        Ok(read) => read,
+        Err(e) => exit_with_error(e),
+    };
+    let parsed = match solution.parse_any(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => exit_with_error(e.with_file(path)),
+    };
+    for part in Part::BOTH {
+        println!(
+            "part {}: {}",
+            part,
+            solution.solve_any(part, parsed.as_ref())
+        );
+    }
+}
+
+fn exit_with_error(e: impl Display) -> ! {
+    eprintln!("error: {}", e);
+    std::process::exit(1);
+}
@@ -0,0 +1,80 @@
+//! Helpers for tests that talk to the site, built with the `test-util` feature.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// What a [`StubServer`] was sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// e.g. `GET /2021/day/1/input HTTP/1.1`
+    pub line: String,
+    pub headers: Vec<String>,
+    pub body: String,
+}
+
+impl Request {
+    /// Whether the request had this header, ignoring case, e.g. `cookie: session=abc123`.
+    pub fn has_header(&self, header: &str) -> bool {
+        self.headers.iter().any(|h| h.eq_ignore_ascii_case(header))
+    }
+}
+
+/// Stands in for the site, answering a single request on a local port.
+pub struct StubServer {
+    pub url: String,
+    handle: JoinHandle<Request>,
+}
+
+impl StubServer {
+    /// Answers the first request with `status`, e.g. `200 OK`, and `body`.
+    pub fn start(status: &str, body: &str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut BufReader::new(&stream));
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        StubServer { url, handle }
+    }
+
+    /// Waits for the request to be answered, and hands back what was sent.
+    pub fn request(self) -> Request {
+        self.handle.join().unwrap()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut lines = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line.to_string());
+    }
+    let line = lines.remove(0);
+
+    let content_length = lines
+        .iter()
+        .filter_map(|h| h.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .map_or(0, |(_, value)| value.trim().parse().unwrap());
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    Request {
+        line,
+        headers: lines,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
@@ -0,0 +1,18 @@
+//! Logging through `tracing`, for the runner and each day's own binary.
+//!
+//! Nothing is logged unless `AOC_LOG` asks for it, using `tracing_subscriber`'s env filter syntax.
+//! Events are logged under the crate they come from, so `AOC_LOG=aoc08=trace` logs everything
+//! day 8 does without touching any other day. Parsing and solving each part run in `parse`,
+//! `part1` and `part2` spans, and `AOC_LOG=aoc_core=info` logs how long each one took.
+
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Logs to stderr, filtered by `AOC_LOG`. Does nothing if a subscriber is already set up.
+pub fn init() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_env("AOC_LOG"))
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
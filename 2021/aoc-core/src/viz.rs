@@ -0,0 +1,295 @@
+//! Watching a day's simulation play out in the terminal, one frame per step.
+//!
+//! A day implements [`Visualize`] to turn its input into [`Frame`]s, and a [`Player`] draws them
+//! with ANSI colors, waiting between frames. While it plays, Enter pauses. While paused, Enter
+//! steps a frame at a time, `c` carries on playing and `q` quits.
+
+use crate::grid::Grid;
+use crate::{AnySolution, Part, Solution};
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Clears the screen and moves the cursor to the top left, so each frame draws over the last.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+const RESET: &str = "\x1b[0m";
+
+/// The standard terminal colors, plus gray for things in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 97,
+            Color::Gray => 90,
+        }
+    }
+}
+
+/// A char drawn in a frame, in the terminal's own color unless it's given one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    pub fn new(ch: char, color: Color) -> Self {
+        Cell {
+            ch,
+            color: Some(color),
+        }
+    }
+
+    pub fn plain(ch: char) -> Self {
+        Cell { ch, color: None }
+    }
+}
+
+/// Just the char, so a `Grid<Cell>` displays as plain text.
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ch)
+    }
+}
+
+/// One step of a simulation, drawn as a grid of chars.
+pub trait Frame {
+    fn draw(&self) -> Grid<Cell>;
+
+    /// A line shown above the frame, e.g. which step it is.
+    fn caption(&self) -> String {
+        String::new()
+    }
+}
+
+/// Draws a frame, with escape codes for each change of color if `color` is set.
+pub fn render(canvas: &Grid<Cell>, color: bool) -> String {
+    if !color {
+        return canvas.to_string();
+    }
+
+    let mut out = String::new();
+    for (i, row) in canvas.iter_rows().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let mut current = None;
+        for cell in row {
+            if cell.color != current {
+                out.push_str(RESET);
+                if let Some(c) = cell.color {
+                    out.push_str(&format!("\x1b[{}m", c.ansi_code()));
+                }
+                current = cell.color;
+            }
+            out.push(cell.ch);
+        }
+        if current.is_some() {
+            out.push_str(RESET);
+        }
+    }
+    out
+}
+
+/// The initial state, then the state after each step, stopping after the first step that
+/// returns false.
+pub fn steps<S: Clone>(
+    initial: S,
+    mut step: impl FnMut(&mut S) -> bool,
+) -> impl Iterator<Item = S> {
+    let mut next = Some(initial);
+    let mut running = true;
+    std::iter::from_fn(move || {
+        let state = next.take()?;
+        // the step that stops the simulation still gets shown, it's where things ended up
+        if running {
+            let mut stepped = state.clone();
+            running = step(&mut stepped);
+            next = Some(stepped);
+        }
+        Some(state)
+    })
+}
+
+enum Control {
+    Enter,
+    Continue,
+    Quit,
+}
+
+/// Plays frames in the terminal, and optionally writes each one out to a text file.
+#[derive(Debug, Clone)]
+pub struct Player {
+    /// How long each frame stays up while playing.
+    pub delay: Duration,
+    /// Start paused, waiting for Enter before each frame.
+    pub paused: bool,
+    pub color: bool,
+    /// Where to write `frame-0000.txt` and so on, without colors.
+    pub dump_dir: Option<PathBuf>,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Player {
+            delay: Duration::from_millis(100),
+            paused: false,
+            color: true,
+            dump_dir: None,
+        }
+    }
+}
+
+impl Player {
+    /// Shows every frame in turn, returning how many were shown before the end or quitting.
+    pub fn play<F: Frame>(&self, frames: impl IntoIterator<Item = F>) -> io::Result<usize> {
+        if let Some(dir) = &self.dump_dir {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut controls = Player::controls();
+        let mut paused = self.paused && controls.is_some();
+        let mut out = io::stdout().lock();
+        let mut shown = 0;
+        for (i, frame) in frames.into_iter().enumerate() {
+            let canvas = frame.draw();
+            let caption = frame.caption();
+            if let Some(dir) = &self.dump_dir {
+                let path = dir.join(format!("frame-{:04}.txt", i));
+                fs::write(&path, format!("{}\n{}\n", caption, canvas))
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+            }
+
+            write!(out, "{}", CLEAR)?;
+            writeln!(out, "{}", caption)?;
+            writeln!(out, "{}", render(&canvas, self.color))?;
+            match (&controls, paused) {
+                (None, _) => writeln!(out, "frame {}", i)?,
+                (Some(_), false) => writeln!(out, "frame {} (enter pauses)", i)?,
+                (Some(_), true) => {
+                    writeln!(out, "frame {} (enter steps, c continues, q quits)", i)?
+                }
+            }
+            out.flush()?;
+            shown += 1;
+
+            let control = match &controls {
+                None => {
+                    thread::sleep(self.delay);
+                    continue;
+                }
+                Some(receiver) if paused => {
+                    receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+                }
+                Some(receiver) => receiver.recv_timeout(self.delay),
+            };
+            match control {
+                Ok(Control::Quit) => break,
+                Ok(Control::Continue) => paused = false,
+                Ok(Control::Enter) => paused = true,
+                Err(RecvTimeoutError::Timeout) => {}
+                // stdin closed, carry on without controls
+                Err(RecvTimeoutError::Disconnected) => {
+                    controls = None;
+                    paused = false;
+                }
+            }
+        }
+        Ok(shown)
+    }
+
+    // Reads controls off stdin a line at a time, if someone's there to type them. The thread is
+    // left blocked on stdin once playing is done, it goes when the process exits.
+    fn controls() -> Option<Receiver<Control>> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let control = match line.as_deref().map(str::trim) {
+                    Ok("q") | Err(_) => Control::Quit,
+                    Ok("c") => Control::Continue,
+                    Ok(_) => Control::Enter,
+                };
+                if sender.send(control).is_err() {
+                    break;
+                }
+            }
+        });
+        Some(receiver)
+    }
+}
+
+/// A day whose simulation can be watched, alongside solving it.
+pub trait Visualize: Solution {
+    /// Every frame of the simulation that solves `part`, from the input onwards.
+    fn frames(&self, part: Part, input: &Self::Input) -> impl Iterator<Item = impl Frame>;
+}
+
+/// Object safe version of [`Visualize`], like [`AnySolution`] is for [`Solution`].
+pub trait AnyVisualization: AnySolution {
+    fn play_any(&self, part: Part, input: &dyn Any, player: &Player) -> io::Result<usize>;
+}
+
+impl<V> AnyVisualization for V
+where
+    V: Visualize + Sync,
+    V::Input: 'static,
+{
+    fn play_any(&self, part: Part, input: &dyn Any, player: &Player) -> io::Result<usize> {
+        let input = input
+            .downcast_ref::<V::Input>()
+            .expect("input was parsed by a different solution");
+        player.play(self.frames(part, input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Pos;
+
+    #[test]
+    fn test_render() {
+        let canvas = Grid::from_fn(2, 3, |pos| match pos {
+            Pos { row: 0, col: 0 } => Cell::new('#', Color::Red),
+            Pos { row: 0, col: 1 } => Cell::new('#', Color::Red),
+            _ => Cell::plain('.'),
+        });
+        assert_eq!(render(&canvas, false), "##.\n...");
+        assert_eq!(render(&canvas, true), "\x1b[0m\x1b[31m##\x1b[0m.\n...");
+    }
+
+    #[test]
+    fn test_steps() {
+        let counted: Vec<u32> = steps(0, |n| {
+            *n += 1;
+            *n < 3
+        })
+        .collect();
+        assert_eq!(counted, [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_core::cache::InputCache;
+use aoc_core::site::Site;
+use aoc_core::test_util::StubServer;
+
+#[test]
+fn fetches_missing_input_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = StubServer::start("200 OK", "1\n2\n3\n");
+    let site = Site::new(server.url.clone(), Some("abc123".to_string()));
+    let cache = InputCache::new(dir.path(), site);
+
+    assert_eq!(cache.get(2021, 7).unwrap(), "1\n2\n3\n");
+    let request = server.request();
+    assert_eq!(request.line, "GET /2021/day/7/input HTTP/1.1");
+    assert!(request.has_header("cookie: session=abc123"));
+
+    // The server is gone, so this has to come from the cache
+    assert_eq!(cache.path(2021, 7), dir.path().join("2021/7.txt"));
+    assert_eq!(cache.get(2021, 7).unwrap(), "1\n2\n3\n");
+}
+
+#[test]
+fn failed_fetch_is_not_cached() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = StubServer::start("400 Bad Request", "Puzzle inputs differ by user.");
+    let site = Site::new(server.url.clone(), Some("expired".to_string()));
+    let cache = InputCache::new(dir.path(), site);
+
+    let e = cache.get(2021, 7).unwrap_err();
+    server.request();
+    assert!(e.to_string().contains("400"), "{}", e);
+    assert!(!cache.path(2021, 7).exists());
+}
+
+#[test]
+fn no_session_token() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = InputCache::new(dir.path(), Site::new("http://127.0.0.1:1", None));
+
+    let e = cache.get(2021, 7).unwrap_err();
+    assert!(e.to_string().contains("AOC_SESSION"), "{}", e);
+}
@@ -0,0 +1,65 @@
+//! Benchmarks parsing and each part of every day against its input.txt.
+//!
+//! Criterion keeps the last run under target/criterion and reports changes against it.
+//! To compare against a fixed point instead (e.g. before starting on an optimization):
+//!
+//!     cargo bench -p aoc -- --save-baseline before
+//!     cargo bench -p aoc -- --baseline before
+//!
+//! Benchmarks are named `dayNN/parse`, `dayNN/part1` and `dayNN/part2`, so a single day
+//! can be picked out with a filter, e.g. `cargo bench -p aoc -- day15`.
+
+use aoc_core::{input, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+// Days that take long enough per iteration that the default 100 samples would take forever
+const SLOW_DAYS: [u8; 4] = [5, 12, 19, 23];
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, solution: S) {
+    let input = input::read_day_input(day, "input").expect("couldn't open file");
+    let parsed = solution.parse(&input).expect("couldn't parse input");
+
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+    if SLOW_DAYS.contains(&day) {
+        group.sample_size(10);
+    }
+
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse(black_box(&input)).unwrap())
+    });
+    group.bench_function("part1", |b| b.iter(|| solution.part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| solution.part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day(c, 1, aoc01::Day01);
+    bench_day(c, 2, aoc02::Day02);
+    bench_day(c, 3, aoc03::Day03);
+    bench_day(c, 4, aoc04::Day04);
+    bench_day(c, 5, aoc05::Day05);
+    bench_day(c, 6, aoc06::Day06);
+    bench_day(c, 7, aoc07::Day07);
+    bench_day(c, 8, aoc08::Day08);
+    bench_day(c, 9, aoc09::Day09);
+    bench_day(c, 10, aoc10::Day10);
+    bench_day(c, 11, aoc11::Day11);
+    bench_day(c, 12, aoc12::Day12);
+    bench_day(c, 13, aoc13::Day13);
+    bench_day(c, 14, aoc14::Day14);
+    bench_day(c, 15, aoc15::Day15);
+    bench_day(c, 16, aoc16::Day16);
+    bench_day(c, 17, aoc17::Day17);
+    bench_day(c, 18, aoc18::Day18);
+    bench_day(c, 19, aoc19::Day19);
+    bench_day(c, 20, aoc20::Day20);
+    bench_day(c, 21, aoc21::Day21);
+    bench_day(c, 22, aoc22::Day22);
+    bench_day(c, 23, aoc23::Day23);
+    bench_day(c, 24, aoc24::Day24);
+    bench_day(c, 25, aoc25::Day25);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
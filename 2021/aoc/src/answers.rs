@@ -0,0 +1,42 @@
+use crate::days;
+use aoc_core::Part;
+use std::fs;
+
+/// Expected answers from a year's `answers.toml`, keyed by day, input file name and part.
+pub struct Answers {
+    table: toml::Table,
+}
+
+impl Answers {
+    pub fn for_year(year: u16) -> Result<Answers, String> {
+        let path = days::year_dir(year)
+            .ok_or_else(|| format!("no answers for {}", year))?
+            .join("answers.toml");
+        let contents =
+            fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        contents.parse()
+    }
+
+    pub fn expected(&self, day: u8, input_name: &str, part: Part) -> Option<String> {
+        let value = self
+            .table
+            .get(&format!("day{:02}", day))?
+            .get(input_name)?
+            .get(format!("part{}", part))?;
+
+        // Plain numbers are fine too, even though everything gets compared as a string
+        match value {
+            toml::Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table = s.parse::<toml::Table>().map_err(|e| e.to_string())?;
+        Ok(Answers { table })
+    }
+}
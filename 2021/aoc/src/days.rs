@@ -0,0 +1,106 @@
+use aoc02::navigation::{self, NavigationModel, State};
+use aoc_core::image::AnyExportImage;
+use aoc_core::viz::AnyVisualization;
+use aoc_core::{AnySolution, Solution};
+use std::any::Any;
+use std::path::PathBuf;
+
+/// Every solved 2021 day, in order. `aoc new` adds new days here.
+pub const DAYS_2021: &[(u8, &dyn AnySolution)] = &[
+    (1, &aoc01::Day01),
+    (2, &aoc02::Day02),
+    (3, &aoc03::Day03),
+    (4, &aoc04::Day04),
+    (5, &aoc05::Day05),
+    (6, &aoc06::Day06),
+    (7, &aoc07::Day07),
+    (8, &aoc08::Day08),
+    (9, &aoc09::Day09),
+    (10, &aoc10::Day10),
+    (11, &aoc11::Day11),
+    (12, &aoc12::Day12),
+    (13, &aoc13::Day13),
+    (14, &aoc14::Day14),
+    (15, &aoc15::Day15),
+    (16, &aoc16::Day16),
+    (17, &aoc17::Day17),
+    (18, &aoc18::Day18),
+    (19, &aoc19::Day19),
+    (20, &aoc20::Day20),
+    (21, &aoc21::Day21),
+    (22, &aoc22::Day22),
+    (23, &aoc23::Day23),
+    (24, &aoc24::Day24),
+    (25, &aoc25::Day25),
+];
+
+/// Looks up the days solved for a year.
+pub fn solutions(year: u16) -> Option<&'static [(u8, &'static dyn AnySolution)]> {
+    match year {
+        2021 => Some(DAYS_2021),
+        _ => None,
+    }
+}
+
+/// Looks up a single day's solution.
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn AnySolution> {
+    solutions(year)?
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, solution)| *solution)
+}
+
+/// Looks up a day with a simulation to watch, for the days that implement `Visualize`.
+pub fn visualization(year: u16, day: u8) -> Option<&'static dyn AnyVisualization> {
+    match (year, day) {
+        (2021, 11) => Some(&aoc11::Day11),
+        (2021, 13) => Some(&aoc13::Day13),
+        (2021, 20) => Some(&aoc20::Day20),
+        (2021, 23) => Some(&aoc23::Day23),
+        (2021, 25) => Some(&aoc25::Day25),
+        _ => None,
+    }
+}
+
+/// Looks up a day that can save its state as an image, for the days that implement
+/// `ExportImage`.
+pub fn image_export(year: u16, day: u8) -> Option<&'static dyn AnyExportImage> {
+    match (year, day) {
+        (2021, 5) => Some(&aoc05::Day05),
+        (2021, 9) => Some(&aoc09::Day09),
+        (2021, 15) => Some(&aoc15::Day15),
+        (2021, 20) => Some(&aoc20::Day20),
+        _ => None,
+    }
+}
+
+/// Where each named navigation model ends up on a day's parsed input.
+pub type Navigate = fn(&dyn Any, &[String]) -> Result<Vec<State>, String>;
+
+/// Looks up a day that steers the submarine with a `NavigationModel`, for the days whose models
+/// can be picked by name, e.g. `aim+drag=10`.
+pub fn navigation(year: u16, day: u8) -> Option<Navigate> {
+    match (year, day) {
+        (2021, 2) => Some(|parsed, models| {
+            let instructions = parsed
+                .downcast_ref::<<aoc02::Day02 as Solution>::Input>()
+                .expect("input was parsed by a different solution");
+            models
+                .iter()
+                .map(|name| {
+                    let model = navigation::by_name(name)?;
+                    (model.navigate(instructions)).map_err(|e| format!("{}: {}", name, e))
+                })
+                .collect()
+        }),
+        _ => None,
+    }
+}
+
+/// Root of the Cargo workspace holding a year's days.
+pub fn year_dir(year: u16) -> Option<PathBuf> {
+    match year {
+        2021 => Some(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")),
+        _ => None,
+    }
+}
@@ -0,0 +1,6 @@
+//! Runs the solutions for each year, and checks them against recorded answers.
+
+pub mod answers;
+pub mod days;
+pub mod scaffold;
+pub mod submit;
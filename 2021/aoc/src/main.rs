@@ -0,0 +1,474 @@
+use aoc::submit::{self, History};
+use aoc::{days, scaffold};
+use aoc02::navigation::{self, State};
+use aoc_core::cache::InputCache;
+use aoc_core::input::{self, InputSource};
+use aoc_core::viz::Player;
+use aoc_core::{AnySolution, Part};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Parser)]
+#[command(
+    about = "Runs Advent of Code solutions",
+    after_help = "Set AOC_LOG to log what solutions are doing, e.g. AOC_LOG=aoc08=trace"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Watch a day's simulation play out in the terminal
+    Viz(VizArgs),
+    /// Create the crate for a new day and add it to the workspace
+    New {
+        /// Puzzle year
+        year: u16,
+
+        /// Puzzle day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a part of a day and submit the answer to the site
+    Submit {
+        /// Puzzle year
+        year: u16,
+
+        /// Puzzle day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part to submit (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Puzzle year
+    #[arg(default_value_t = 2021)]
+    year: u16,
+
+    /// Puzzle day, required unless --all is given
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only solve this part (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Name of a file in the day's input/ dir without the .txt extension, a path to any file,
+    /// or - to read from stdin. Only names can be used with --all
+    #[arg(long, default_value = "input")]
+    input: InputSource,
+
+    /// Solve every day of the year
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// How to print answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Print each day's parsed input to stderr before solving it
+    #[arg(long, short)]
+    verbose: bool,
+
+    /// Number of days to solve at once, or 0 for one per CPU. Answers are printed in day order
+    #[arg(long, short, default_value_t = 1)]
+    jobs: usize,
+
+    /// Save the state that answers the part (2 unless --part is given) as a .ppm or .png image
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    export_image: Option<PathBuf>,
+
+    /// Also print where the submarine ends up navigating this way, e.g. simple, aim or
+    /// aim+drag=10+max-depth=500. Can be given more than once, for days that navigate
+    #[arg(long = "model", value_name = "NAME", conflicts_with = "all")]
+    models: Vec<String>,
+}
+
+#[derive(Args)]
+struct VizArgs {
+    /// Puzzle year
+    year: u16,
+
+    /// Puzzle day
+    day: u8,
+
+    /// Watch the simulation that solves this part (1 or 2)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Name of a file in the day's input/ dir without the .txt extension, or a path to any file
+    #[arg(long, default_value = "input")]
+    input: InputSource,
+
+    /// Milliseconds to show each frame for
+    #[arg(long, default_value_t = 100)]
+    delay: u64,
+
+    /// Start paused, and step through frames with enter
+    #[arg(long)]
+    step: bool,
+
+    /// Write each frame to a text file in this dir
+    #[arg(long, value_name = "DIR")]
+    dump: Option<PathBuf>,
+
+    /// Draw without colors
+    #[arg(long)]
+    no_color: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// A line per part, with the time it took
+    Text,
+    /// A JSON object per part on its own line, with parse and solve times in milliseconds
+    Json,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    aoc_core::trace::init();
+    let result = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Viz(args) => viz(&args),
+        Command::New { year, day } => scaffold::new_day(year, day),
+        Command::Submit { year, day, part } => submit(year, day, part),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let solutions =
+        days::solutions(args.year).ok_or_else(|| format!("no solutions for {}", args.year))?;
+
+    let selected_days: Vec<(u8, &dyn AnySolution)> = match args.day {
+        Some(day) => {
+            let solution = days::solution(args.year, day)
+                .ok_or_else(|| format!("no solution for {} day {}", args.year, day))?;
+            vec![(day, solution)]
+        }
+        None => solutions.to_vec(),
+    };
+
+    if args.all && !matches!(args.input, InputSource::Named(_)) {
+        return Err(format!(
+            "--input {} can't be used with --all, each day needs its own input",
+            args.input
+        ));
+    }
+
+    if args.export_image.is_some() {
+        let day = args.day.expect("--export-image conflicts with --all");
+        if days::image_export(args.year, day).is_none() {
+            return Err(format!("{} day {} can't export images", args.year, day));
+        }
+    }
+
+    if !args.models.is_empty() {
+        let day = args.day.expect("--model conflicts with --all");
+        if days::navigation(args.year, day).is_none() {
+            return Err(format!("{} day {} doesn't navigate", args.year, day));
+        }
+        for name in &args.models {
+            navigation::by_name(name)?;
+        }
+    }
+
+    let parts: Vec<Part> = match args.part {
+        Some(n) => vec![Part::try_from(n)?],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.jobs)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let cache = InputCache::from_env();
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    let start = Instant::now();
+    let mut total = Duration::ZERO;
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            pool.install(|| {
+                selected_days.par_iter().enumerate().for_each_with(
+                    sender,
+                    |sender, (i, &(day, solution))| {
+                        // Days after one that failed won't get printed, don't bother solving them
+                        let outcome = if stop.load(Ordering::Relaxed) {
+                            None
+                        } else {
+                            Some(solve_day(args, &cache, day, solution, &parts))
+                        };
+                        let _ = sender.send((i, outcome));
+                    },
+                );
+            });
+        });
+
+        // Days finish in any order, hold on to them until every day before has been printed
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        for (i, outcome) in receiver {
+            finished.insert(i, outcome);
+            while let Some(outcome) = finished.remove(&next) {
+                match outcome.expect("days before a failure are always solved") {
+                    Ok(DayOutcome::Solved(report)) => total += report.print(args),
+                    Ok(DayOutcome::Missing(day)) => report_missing(args, day),
+                    Err(e) => {
+                        stop.store(true, Ordering::Relaxed);
+                        return Err(e);
+                    }
+                }
+                next += 1;
+            }
+        }
+        Ok(())
+    })?;
+
+    if args.all && args.format == Format::Text {
+        if args.jobs == 1 {
+            println!("total: {:.2?}", total);
+        } else {
+            println!("total: {:.2?} ({:.2?} elapsed)", total, start.elapsed());
+        }
+    }
+    Ok(())
+}
+
+/// What came of a day, kept until it's the day's turn to be printed.
+enum DayOutcome {
+    Solved(DayReport),
+    /// --all was given the name of an input this day doesn't have.
+    Missing(u8),
+}
+
+// Days without the named input are skipped rather than failing the whole run, most only have
+// some of the extra inputs
+fn report_missing(args: &RunArgs, day: u8) {
+    let message = format!(
+        "{} day {:02}: skipped, no input/{}.txt",
+        args.year, day, args.input
+    );
+    match args.format {
+        Format::Text => println!("{}", message),
+        Format::Json => eprintln!("{}", message),
+    }
+}
+
+/// Everything solving a day produced.
+struct DayReport {
+    year: u16,
+    day: u8,
+    parsed_input: Option<String>,
+    parse_time: Duration,
+    answers: Vec<(Part, String, Duration)>,
+    /// Where each navigation model given with --model ends up.
+    model_ends: Vec<(String, State)>,
+}
+
+impl DayReport {
+    /// Prints the day's answers, returning how long it took to solve.
+    fn print(&self, args: &RunArgs) -> Duration {
+        if let Some(parsed_input) = &self.parsed_input {
+            eprintln!("{} day {:02} input: {}", self.year, self.day, parsed_input);
+        }
+
+        let mut total = self.parse_time;
+        for (part, answer, solve_time) in &self.answers {
+            let (year, day, part, solve_time) = (self.year, self.day, *part, *solve_time);
+            match args.format {
+                Format::Text => print_text(year, day, part, answer, solve_time),
+                Format::Json => print_json(year, day, part, answer, self.parse_time, solve_time),
+            }
+            total += solve_time;
+        }
+        for (model, end) in &self.model_ends {
+            match args.format {
+                Format::Text => println!("{} day {:02} {}: {}", self.year, self.day, model, end),
+                Format::Json => print_json_model_end(self.year, self.day, model, end),
+            }
+        }
+        total
+    }
+}
+
+fn solve_day(
+    args: &RunArgs,
+    cache: &InputCache,
+    day: u8,
+    solution: &dyn AnySolution,
+    parts: &[Part],
+) -> Result<DayOutcome, String> {
+    let _span = tracing::info_span!("day", year = args.year, day).entered();
+    if let InputSource::Named(name) = &args.input {
+        // the puzzle input is fetched when it's missing, nothing else can be
+        if args.all && name != "input" && !input::day_input_path(day, name).exists() {
+            return Ok(DayOutcome::Missing(day));
+        }
+    }
+    let (path, input) = args
+        .input
+        .read(cache, args.year, day)
+        .map_err(|e| e.to_string())?;
+
+    let start = Instant::now();
+    let parsed = solution
+        .parse_any(&input)
+        .map_err(|e| e.with_file(&path).to_string())?;
+    let parse_time = start.elapsed();
+
+    let parsed_input = args.verbose.then(|| solution.debug_any(parsed.as_ref()));
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solution.solve_any(part, parsed.as_ref());
+            (part, answer, start.elapsed())
+        })
+        .collect();
+
+    if let Some(path) = &args.export_image {
+        let exporter = days::image_export(args.year, day).expect("checked before solving");
+        let part = match args.part {
+            Some(n) => Part::try_from(n)?,
+            None => Part::Two,
+        };
+        exporter
+            .export_image_any(part, parsed.as_ref(), path)
+            .map_err(|e| e.to_string())?;
+    }
+
+    let model_ends = match days::navigation(args.year, day) {
+        Some(navigate) if !args.models.is_empty() => {
+            let ends = navigate(parsed.as_ref(), &args.models)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+            args.models.iter().cloned().zip(ends).collect()
+        }
+        _ => vec![],
+    };
+
+    Ok(DayOutcome::Solved(DayReport {
+        year: args.year,
+        day,
+        parsed_input,
+        parse_time,
+        answers,
+        model_ends,
+    }))
+}
+
+fn viz(args: &VizArgs) -> Result<(), String> {
+    let visualization = days::visualization(args.year, args.day)
+        .ok_or_else(|| format!("no visualization for {} day {}", args.year, args.day))?;
+    let part = Part::try_from(args.part)?;
+
+    // Controls are read from stdin, so the input can't come from there too
+    if args.input == InputSource::Stdin {
+        return Err("--input - can't be used to visualize, give a name or path".to_string());
+    }
+    let (path, input) = args
+        .input
+        .read(&InputCache::from_env(), args.year, args.day)
+        .map_err(|e| e.to_string())?;
+    let parsed = visualization
+        .parse_any(&input)
+        .map_err(|e| e.with_file(&path).to_string())?;
+
+    let player = Player {
+        delay: Duration::from_millis(args.delay),
+        paused: args.step,
+        color: !args.no_color,
+        dump_dir: args.dump.clone(),
+    };
+    visualization
+        .play_any(part, parsed.as_ref(), &player)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: u8) -> Result<(), String> {
+    let solution =
+        days::solution(year, day).ok_or_else(|| format!("no solution for {} day {}", year, day))?;
+    let part = Part::try_from(part)?;
+
+    let cache = InputCache::from_env();
+    let (path, input) = InputSource::Named("input".to_string())
+        .read(&cache, year, day)
+        .map_err(|e| e.to_string())?;
+    let parsed = solution
+        .parse_any(&input)
+        .map_err(|e| e.with_file(&path).to_string())?;
+    let answer = solution.solve_any(part, parsed.as_ref());
+
+    let mut history = History::load(cache.year_dir(year).join(format!("{}.answers.toml", day)))?;
+    let verdict = submit::submit(cache.site(), &mut history, year, day, part, &answer)?;
+    println!(
+        "{} day {:02} part {}: {} is {}",
+        year, day, part, answer, verdict
+    );
+    Ok(())
+}
+
+fn print_text(year: u16, day: u8, part: Part, answer: &str, elapsed: Duration) {
+    // Some answers are drawn out over multiple lines, start those on their own line
+    let separator = if answer.contains('\n') { "\n" } else { " " };
+    println!(
+        "{} day {:02} part {}:{}{} ({:.2?})",
+        year, day, part, separator, answer, elapsed
+    );
+}
+
+fn print_json(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    parse_time: Duration,
+    solve_time: Duration,
+) {
+    let result = serde_json::json!({
+        "year": year,
+        "day": day,
+        "part": u8::from(part),
+        "answer": answer,
+        "parse_ms": parse_time.as_secs_f64() * 1000.0,
+        "solve_ms": solve_time.as_secs_f64() * 1000.0,
+    });
+    println!("{}", result);
+}
+
+fn print_json_model_end(year: u16, day: u8, model: &str, end: &State) {
+    let result = serde_json::json!({
+        "year": year,
+        "day": day,
+        "model": model,
+        "distance": end.distance,
+        "depth": end.depth,
+        "aim": end.aim,
+        // like answers, as a string, since it can be too big for a JSON number
+        "product": end.product().to_string(),
+    });
+    println!("{}", result);
+}
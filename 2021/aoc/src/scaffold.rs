@@ -0,0 +1,123 @@
+use crate::days;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+
+// Each file a new day needs to be listed in, along with the line that lists it.
+const REGISTRATIONS: [(&str, &str); 5] = [
+    ("Cargo.toml", "    \"aoc{NN}\","),
+    ("Cargo.toml", "aoc{NN} = { path = \"aoc{NN}\" }"),
+    ("aoc/Cargo.toml", "aoc{NN} = { workspace = true }"),
+    ("aoc/src/days.rs", "    ({N}, &aoc{NN}::Day{NN}),"),
+    (
+        "aoc/benches/days.rs",
+        "    bench_day(c, {N}, aoc{NN}::Day{NN});",
+    ),
+];
+
+/// Creates the crate for a new day from the templates, and registers it with the workspace,
+/// the runner and the benchmarks.
+pub fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let year_dir = days::year_dir(year).ok_or_else(|| format!("no workspace for {}", year))?;
+    let day_dir = year_dir.join(format!("aoc{:02}", day));
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    // Check every registration up front, so a failure doesn't leave the workspace half updated
+    let mut registered: Vec<(PathBuf, String)> = Vec::new();
+    for (file, template) in REGISTRATIONS {
+        let path = year_dir.join(file);
+        let i = match registered.iter().position(|(p, _)| *p == path) {
+            Some(i) => i,
+            None => {
+                let contents = read(&path)?;
+                registered.push((path, contents));
+                registered.len() - 1
+            }
+        };
+        registered[i].1 =
+            register(&registered[i].1, template, day).map_err(|e| format!("{}: {}", file, e))?;
+    }
+
+    fs::create_dir_all(day_dir.join("src")).map_err(|e| e.to_string())?;
+    fs::create_dir_all(day_dir.join("input")).map_err(|e| e.to_string())?;
+    write(&day_dir.join("Cargo.toml"), &render(CARGO_TOML, day))?;
+    write(&day_dir.join("src/main.rs"), &render(MAIN_RS, day))?;
+    write(&day_dir.join("src/lib.rs"), &render(LIB_RS, day))?;
+    write(&day_dir.join("input/test.txt"), "")?;
+    for (path, contents) in registered {
+        write(&path, &contents)?;
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+}
+
+// Inserts the line for `day` among the lines already there for other days, keeping them in day
+// order.
+fn register(contents: &str, template: &str, day: u8) -> Result<String, String> {
+    let line = render(template, day);
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&line.as_str()) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    let day_of = |l: &str| (1..=25).find(|&d| render(template, d) == l);
+    let position = match lines
+        .iter()
+        .rposition(|l| day_of(l).is_some_and(|d| d < day))
+    {
+        Some(i) => i + 1,
+        None => lines
+            .iter()
+            .position(|l| day_of(l).is_some())
+            .ok_or_else(|| format!("no other days to add day {} next to", day))?,
+    };
+    lines.insert(position, &line);
+
+    let mut registered = lines.join("\n");
+    registered.push('\n');
+    Ok(registered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "    \"aoc{NN}\",";
+
+    #[test]
+    fn test_register_in_day_order() {
+        let contents = "members = [\n    \"aoc\",\n    \"aoc01\",\n    \"aoc03\",\n]\n";
+        assert_eq!(
+            register(contents, TEMPLATE, 2).unwrap(),
+            "members = [\n    \"aoc\",\n    \"aoc01\",\n    \"aoc02\",\n    \"aoc03\",\n]\n"
+        );
+        assert_eq!(
+            register(contents, TEMPLATE, 25).unwrap(),
+            "members = [\n    \"aoc\",\n    \"aoc01\",\n    \"aoc03\",\n    \"aoc25\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_register_twice() {
+        let contents = "members = [\n    \"aoc01\",\n]\n";
+        assert!(register(contents, TEMPLATE, 1).is_err());
+    }
+}
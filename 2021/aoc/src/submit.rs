@@ -0,0 +1,251 @@
+use aoc_core::site::Site;
+use aoc_core::Part;
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict off the page the site responds to an answer with. Errors with the page's
+    /// message if it didn't judge the answer, e.g. when answering again too soon.
+    pub fn from_page(page: &str) -> Result<Verdict, String> {
+        let message = article_text(page);
+        if message.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Ok(Verdict::TooHigh)
+            } else if message.contains("too low") {
+                Ok(Verdict::TooLow)
+            } else {
+                Ok(Verdict::Wrong)
+            }
+        } else {
+            Err(format!("answer wasn't judged: {}", message))
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            other => Err(format!("unknown verdict: {}", other)),
+        }
+    }
+}
+
+// The site's message is in the page's <article>, strip the markup around it
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Every answer submitted for a day and the verdict it got, so a wrong answer is never sent
+/// twice. Kept as a TOML table of answers to verdicts for each part.
+pub struct History {
+    path: PathBuf,
+    table: toml::Table,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if nothing has been submitted yet.
+    pub fn load(path: impl Into<PathBuf>) -> Result<History, String> {
+        let path = path.into();
+        let table = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|e| format!("{}: {}", path.display(), e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => toml::Table::new(),
+            // anything else would have the next submit write over the history
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        Ok(History { path, table })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn verdicts(&self, part: Part) -> Vec<(String, Verdict)> {
+        let Some(toml::Value::Table(answers)) = self.table.get(&format!("part{}", part)) else {
+            return vec![];
+        };
+        answers
+            .iter()
+            .filter_map(|(answer, verdict)| {
+                let verdict = verdict.as_str()?.parse().ok()?;
+                Some((answer.clone(), verdict))
+            })
+            .collect()
+    }
+
+    /// Checks an answer against what's been submitted before, erroring if it's already known
+    /// to be wrong or the part has already been solved.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        for (submitted, verdict) in self.verdicts(part) {
+            // Numeric answers can be ruled out by ones that were too high or low
+            let order = match (answer.parse::<i64>(), submitted.parse::<i64>()) {
+                (Ok(n), Ok(bound)) => Some(n.cmp(&bound)),
+                _ => None,
+            };
+            match verdict {
+                Verdict::Correct if submitted == answer => {
+                    return Err(format!("{} was already accepted", answer));
+                }
+                Verdict::Correct => {
+                    return Err(format!(
+                        "part {} was already solved with {}",
+                        part, submitted
+                    ));
+                }
+                _ if submitted == answer => {
+                    return Err(format!("{} was already submitted: {}", answer, verdict));
+                }
+                Verdict::TooHigh if order.is_some_and(Ordering::is_ge) => {
+                    return Err(format!("{} was too high, so {} is too", submitted, answer));
+                }
+                Verdict::TooLow if order.is_some_and(Ordering::is_le) => {
+                    return Err(format!("{} was too low, so {} is too", submitted, answer));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) -> Result<(), String> {
+        let answers = self
+            .table
+            .entry(format!("part{}", part))
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if let toml::Value::Table(answers) = answers {
+            answers.insert(answer.to_string(), verdict.to_string().into());
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(&self.path, self.table.to_string())
+            .map_err(|e| format!("{}: {}", self.path.display(), e))
+    }
+}
+
+/// Submits an answer unless the history already rules it out, and records the verdict.
+pub fn submit(
+    site: &Site,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, String> {
+    if answer.contains('\n') {
+        return Err(
+            "answer is drawn over multiple lines, read it off and submit it by hand".into(),
+        );
+    }
+    history.check(part, answer)?;
+
+    let level = part.to_string();
+    let page = site
+        .post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", answer)],
+        )
+        .map_err(|e| e.to_string())?;
+    let verdict = Verdict::from_page(&page)?;
+    history.record(part, answer, verdict)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict_from_page() {
+        let page =
+            |message: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", message);
+        let tests = [
+            (
+                "That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.",
+                Ok(Verdict::Correct),
+            ),
+            (
+                "That's not the right answer; your answer is too high. Please wait one minute.",
+                Ok(Verdict::TooHigh),
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Ok(Verdict::TooLow),
+            ),
+            (
+                "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+                Ok(Verdict::Wrong),
+            ),
+            (
+                "You gave an answer too recently; you have <span>39s</span> left to wait.",
+                Err("answer wasn't judged: You gave an answer too recently; you have 39s left to wait.".to_string()),
+            ),
+        ];
+        for (message, expected) in tests {
+            assert_eq!(Verdict::from_page(&page(message)), expected);
+        }
+    }
+
+    #[test]
+    fn test_history_check() {
+        let mut history = History::load("/nonexistent/history.toml").unwrap();
+        history.table = "[part1]\n100 = \"too high\"\n10 = \"too low\"\n42 = \"wrong\"\n"
+            .parse()
+            .unwrap();
+
+        assert!(history.check(Part::One, "50").is_ok());
+        assert!(history.check(Part::One, "42").is_err());
+        assert!(history.check(Part::One, "100").is_err());
+        assert!(history.check(Part::One, "101").is_err());
+        assert!(history.check(Part::One, "9").is_err());
+        assert!(history.check(Part::Two, "42").is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+//! Runs every day against the inputs recorded in answers.toml.
+//! Run with `--nocapture` to see the full report, including timings.
+
+use aoc::answers::Answers;
+use aoc::days;
+use aoc_core::{input, Part};
+use std::time::{Duration, Instant};
+
+const YEAR: u16 = 2021;
+const INPUT_NAMES: [&str; 2] = ["test", "input"];
+
+#[test]
+fn recorded_answers() {
+    let answers = Answers::for_year(YEAR).expect("couldn't load answers");
+    let solutions = days::solutions(YEAR).unwrap();
+
+    let mut report: Vec<String> = vec![];
+    let mut mismatches = 0;
+    let mut missing = 0;
+    let mut total = Duration::ZERO;
+
+    for &(day, solution) in solutions {
+        for input_name in INPUT_NAMES {
+            let path = input::day_input_path(day, input_name);
+            let expected: Vec<Option<String>> = Part::BOTH
+                .iter()
+                .map(|&part| answers.expected(day, input_name, part))
+                .collect();
+
+            if !path.exists() {
+                // Only worth mentioning if we've got answers for it
+                if expected.iter().any(Option::is_some) {
+                    missing += 1;
+                    report.push(format!("day {:02} {}: MISSING INPUT", day, input_name));
+                }
+                continue;
+            }
+
+            let contents = input::normalize_newlines(input::read_to_string(&path).unwrap());
+            let start = Instant::now();
+            let parsed = match solution.parse_any(&contents) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    mismatches += 1;
+                    report.push(format!(
+                        "day {:02} {}: PARSE ERROR {}",
+                        day,
+                        input_name,
+                        e.with_file(&path)
+                    ));
+                    continue;
+                }
+            };
+            let parse_time = start.elapsed();
+            total += parse_time;
+
+            for (part, expected) in Part::BOTH.into_iter().zip(expected) {
+                let start = Instant::now();
+                let answer = solution.solve_any(part, parsed.as_ref());
+                let solve_time = start.elapsed();
+                total += solve_time;
+
+                let status = match expected {
+                    None => {
+                        missing += 1;
+                        format!("MISSING ANSWER (got {:?})", answer)
+                    }
+                    Some(expected) if expected != answer => {
+                        mismatches += 1;
+                        format!("MISMATCH expected {:?}, got {:?}", expected, answer)
+                    }
+                    Some(_) => "ok".to_string(),
+                };
+                report.push(format!(
+                    "day {:02} {} part {}: {} (parse {:.2?}, solve {:.2?})",
+                    day, input_name, part, status, parse_time, solve_time
+                ));
+            }
+        }
+    }
+
+    report.push(format!(
+        "{} mismatches, {} missing, total {:.2?}",
+        mismatches, missing, total
+    ));
+    let report = report.join("\n");
+    println!("{}", report);
+
+    assert_eq!(mismatches, 0, "answers don't match:\n{}", report);
+}
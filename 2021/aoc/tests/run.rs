@@ -0,0 +1,134 @@
+//! Runs days against every input they ship besides the puzzle input, the way `aoc run --input`
+//! would pick them.
+
+use aoc::days;
+use aoc_core::cache::InputCache;
+use aoc_core::input::{self, InputSource};
+use aoc_core::Part;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::Command;
+
+const YEAR: u16 = 2021;
+
+// Names of the extra .txt inputs in a day's input/ dir, like part2_test for part2_test.txt
+fn extra_input_names(day: u8) -> Vec<String> {
+    let dir = input::day_input_path(day, "input").with_file_name("");
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .filter(|name| name != "input")
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn named_inputs_solve_without_panicking() {
+    let cache = InputCache::from_env();
+    let mut failures = vec![];
+
+    for &(day, solution) in days::solutions(YEAR).unwrap() {
+        for name in extra_input_names(day) {
+            let source = InputSource::Named(name.clone());
+            let (_, contents) = source.read(&cache, YEAR, day).unwrap();
+            // not every file is puzzle input, e.g. notes on it, so only panics count
+            let Ok(parsed) = solution.parse_any(&contents) else {
+                continue;
+            };
+            for part in Part::BOTH {
+                let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+                    solution.solve_any(part, parsed.as_ref())
+                }));
+                if solved.is_err() {
+                    failures.push(format!("day {:02} {} part {}", day, name, part));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "panicked: {}", failures.join(", "));
+}
+
+#[test]
+fn degenerate_inputs_are_parse_errors() {
+    let mut failures = vec![];
+    for &(day, solution) in days::solutions(YEAR).unwrap() {
+        for contents in ["", "1\n"] {
+            // anything parse lets through has to solve
+            let Ok(parsed) = solution.parse_any(contents) else {
+                continue;
+            };
+            for part in Part::BOTH {
+                let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+                    solution.solve_any(part, parsed.as_ref())
+                }));
+                if solved.is_err() {
+                    failures.push(format!("day {:02} {:?} part {}", day, contents, part));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "panicked: {}", failures.join(", "));
+}
+
+fn aoc(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    (output.status.success(), stdout + &stderr)
+}
+
+#[test]
+fn run_with_named_input() {
+    let (ok, output) = aoc(&["run", "2021", "23", "--input", "part2_input"]);
+    assert!(ok, "{}", output);
+    assert!(output.contains("2021 day 23 part 2: 43117"), "{}", output);
+}
+
+#[test]
+fn run_all_skips_days_without_the_named_input() {
+    let (ok, output) = aoc(&["run", "--all", "--input", "part2_test", "--part", "1"]);
+    assert!(ok, "{}", output);
+    assert!(
+        output.contains("2021 day 01: skipped, no input/part2_test.txt"),
+        "{}",
+        output
+    );
+    assert!(output.contains("2021 day 22 part 1:"), "{}", output);
+    assert!(output.contains("2021 day 23 part 1:"), "{}", output);
+}
+
+#[test]
+fn run_with_navigation_models() {
+    let (ok, output) = aoc(&[
+        "run",
+        "2021",
+        "2",
+        "--input",
+        "test",
+        "--model",
+        "simple",
+        "--model",
+        "aim+drag=50",
+    ]);
+    assert!(ok, "{}", output);
+    assert!(
+        output.contains("2021 day 02 simple: distance 15, depth 10, aim 0 (150)"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("2021 day 02 aim+drag=50: distance 7, depth 30, aim 10 (210)"),
+        "{}",
+        output
+    );
+
+    let (ok, output) = aoc(&["run", "2021", "1", "--input", "test", "--model", "aim"]);
+    assert!(!ok);
+    assert!(output.contains("2021 day 1 doesn't navigate"), "{}", output);
+}
@@ -0,0 +1,75 @@
+//! Submits answers to a stub server standing in for the site.
+
+use aoc::submit::{self, History, Verdict};
+use aoc_core::site::Site;
+use aoc_core::test_util::StubServer;
+use aoc_core::Part;
+
+// Stands in for the site, responding to a single request with a page holding `message`.
+fn stub_server(message: &str) -> (Site, StubServer) {
+    let body = format!(
+        "<html><main><article><p>{}</p></article></main></html>",
+        message
+    );
+    let server = StubServer::start("200 OK", &body);
+    (
+        Site::new(server.url.clone(), Some("abc123".to_string())),
+        server,
+    )
+}
+
+#[test]
+fn wrong_answer_is_not_resubmitted() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("1.answers.toml");
+    let mut history = History::load(&path).unwrap();
+
+    let (site, server) = stub_server("That's not the right answer; your answer is too high.");
+    let verdict = submit::submit(&site, &mut history, 2021, 1, Part::Two, "1234").unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+    let request = server.request();
+    assert_eq!(request.line, "POST /2021/day/1/answer HTTP/1.1");
+    assert_eq!(request.body, "level=2&answer=1234");
+
+    // Nothing is listening any more, so these must be turned away before reaching the site
+    let mut history = History::load(&path).unwrap();
+    assert_eq!(
+        history.verdicts(Part::Two),
+        [("1234".to_string(), Verdict::TooHigh)]
+    );
+    assert!(submit::submit(&site, &mut history, 2021, 1, Part::Two, "1234").is_err());
+    assert!(submit::submit(&site, &mut history, 2021, 1, Part::Two, "2000").is_err());
+}
+
+#[test]
+fn correct_answer_is_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut history = History::load(dir.path().join("1.answers.toml")).unwrap();
+
+    let (site, server) = stub_server("That's the right answer! You are one gold star closer.");
+    let verdict = submit::submit(&site, &mut history, 2021, 1, Part::One, "1722").unwrap();
+    server.request();
+    assert_eq!(verdict, Verdict::Correct);
+    assert!(history.check(Part::One, "1722").is_err());
+    assert!(history.check(Part::One, "1000").is_err());
+}
+
+#[test]
+fn unjudged_answer_is_not_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut history = History::load(dir.path().join("1.answers.toml")).unwrap();
+
+    let (site, server) = stub_server("You gave an answer too recently; you have 30s left to wait.");
+    let e = submit::submit(&site, &mut history, 2021, 1, Part::One, "1722").unwrap_err();
+    server.request();
+    assert!(e.contains("too recently"), "{}", e);
+    assert!(history.verdicts(Part::One).is_empty());
+    assert!(!history.path().exists());
+}
+
+#[test]
+fn unreadable_history_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    // a directory can't be read as a file, unlike a missing file it isn't an empty history
+    assert!(History::load(dir.path()).is_err());
+}
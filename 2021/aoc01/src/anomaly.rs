@@ -0,0 +1,256 @@
+//! Finding the stretches of a sonar sweep worth a closer look, in one pass over the readings.
+//!
+//! Depths grow going down, so a descent is where the sums of windows of readings keep getting
+//! bigger, and a drop is how much bigger one window is than the window before. Every finding
+//! comes with the indexes of the readings it covers, first to last.
+
+use crate::{SlidingWindow, Trend, WindowSum};
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// The longest run of windows that each went deeper than the one before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Descent {
+    pub readings: RangeInclusive<usize>,
+    /// Number of windows in a row that went deeper.
+    pub steps: usize,
+    /// How much bigger the last window's sum is than the first's.
+    pub depth: usize,
+}
+
+impl Display for Descent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "readings {:?}, {} deeper over {} windows",
+            self.readings, self.depth, self.steps
+        )
+    }
+}
+
+/// The biggest increase in sum from one window to the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowDrop {
+    pub readings: RangeInclusive<usize>,
+    pub depth: usize,
+}
+
+impl Display for WindowDrop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "readings {:?}, {} deeper", self.readings, self.depth)
+    }
+}
+
+/// A reading more than k standard deviations from the mean of the readings just before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deviation {
+    pub index: usize,
+    pub depth: usize,
+    /// The readings the mean and standard deviation were taken over.
+    pub readings: RangeInclusive<usize>,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl Display for Deviation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reading {}: {}, against {:.1} ± {:.1} over readings {:?}",
+            self.index, self.depth, self.mean, self.std_dev, self.readings
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Anomalies {
+    pub longest_descent: Option<Descent>,
+    pub steepest_drop: Option<WindowDrop>,
+    pub deviations: Vec<Deviation>,
+}
+
+/// Looks for anomalies a reading at a time, keeping only the last window of readings.
+#[derive(Debug, Clone)]
+pub struct AnomalyDetector {
+    window: SlidingWindow,
+    size: usize,
+    k: f64,
+    /// The readings before the next one, for the rolling mean.
+    recent: VecDeque<usize>,
+    next_index: usize,
+    prev_window: Option<WindowSum>,
+    /// The first window of the descent the latest window is part of.
+    descent_start: Option<WindowSum>,
+    anomalies: Anomalies,
+}
+
+impl AnomalyDetector {
+    /// Sums readings over windows of `size` for descents and drops, and flags readings more
+    /// than `k` standard deviations from the mean of the `size` readings before them. A single
+    /// reading has no spread to measure against, so windows of 1 don't flag any.
+    pub fn new(size: usize, k: f64) -> Self {
+        AnomalyDetector {
+            window: SlidingWindow::new(size),
+            size,
+            k,
+            recent: VecDeque::with_capacity(size),
+            next_index: 0,
+            prev_window: None,
+            descent_start: None,
+            anomalies: Anomalies::default(),
+        }
+    }
+
+    pub fn push(&mut self, depth: usize) {
+        self.check_deviation(depth);
+        if let Some(window) = self.window.push(depth) {
+            self.check_descent(&window);
+            self.prev_window = Some(window);
+        }
+        self.next_index += 1;
+    }
+
+    pub fn finish(self) -> Anomalies {
+        self.anomalies
+    }
+
+    // Readings covered by the windows from the one starting at `first` to the one at `last`
+    fn readings(&self, first: usize, last: usize) -> RangeInclusive<usize> {
+        first..=last + self.size - 1
+    }
+
+    fn check_descent(&mut self, window: &WindowSum) {
+        if window.trend != Some(Trend::Increase) {
+            self.descent_start = Some(*window);
+            return;
+        }
+
+        // only the first window has no trend, so anything deeper has windows before it
+        let start = self.descent_start.expect("descents start at a window");
+        let prev = self.prev_window.expect("deeper than a window before");
+        let steps = window.start - start.start;
+        if (self.anomalies.longest_descent.as_ref()).is_none_or(|longest| steps > longest.steps) {
+            self.anomalies.longest_descent = Some(Descent {
+                readings: self.readings(start.start, window.start),
+                steps,
+                depth: window.sum - start.sum,
+            });
+        }
+
+        let depth = window.sum - prev.sum;
+        if (self.anomalies.steepest_drop.as_ref()).is_none_or(|steepest| depth > steepest.depth) {
+            self.anomalies.steepest_drop = Some(WindowDrop {
+                readings: self.readings(prev.start, window.start),
+                depth,
+            });
+        }
+    }
+
+    fn check_deviation(&mut self, depth: usize) {
+        if self.recent.len() == self.size {
+            if self.size > 1 {
+                self.check_against_recent(depth);
+            }
+            self.recent.pop_front();
+        }
+        self.recent.push_back(depth);
+    }
+
+    fn check_against_recent(&mut self, depth: usize) {
+        let n = self.size as f64;
+        let mean = self.recent.iter().sum::<usize>() as f64 / n;
+        let variance = (self.recent.iter())
+            .map(|&d| (d as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let std_dev = variance.sqrt();
+        if (depth as f64 - mean).abs() > self.k * std_dev {
+            self.anomalies.deviations.push(Deviation {
+                index: self.next_index,
+                depth,
+                readings: self.next_index - self.size..=self.next_index - 1,
+                mean,
+                std_dev,
+            });
+        }
+    }
+}
+
+/// Looks for anomalies in a whole sweep, stopping at the first reading that fails.
+pub fn detect_anomalies<E>(
+    depths: impl Iterator<Item = Result<usize, E>>,
+    size: usize,
+    k: f64,
+) -> Result<Anomalies, E> {
+    let mut detector = AnomalyDetector::new(size, k);
+    for depth in depths {
+        detector.push(depth?);
+    }
+    Ok(detector.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_depths;
+    use crate::tests::example;
+
+    #[test]
+    fn test_descent_and_drop() {
+        let example = example();
+        let anomalies = detect_anomalies(read_depths(example.as_bytes()), 1, 3.0).unwrap();
+        assert_eq!(
+            anomalies.longest_descent,
+            // the run from 200 to 269 is as long, but the first one found is kept
+            Some(Descent {
+                readings: 0..=3,
+                steps: 3,
+                depth: 11
+            })
+        );
+        assert_eq!(
+            anomalies.steepest_drop,
+            Some(WindowDrop {
+                readings: 5..=6,
+                depth: 33
+            })
+        );
+
+        // 607, 618, 618, 617, 647, 716, 769, 792
+        let anomalies = detect_anomalies(read_depths(example.as_bytes()), 3, 3.0).unwrap();
+        assert_eq!(
+            anomalies.longest_descent,
+            Some(Descent {
+                readings: 3..=9,
+                steps: 4,
+                depth: 175
+            })
+        );
+        assert_eq!(
+            anomalies.steepest_drop,
+            Some(WindowDrop {
+                readings: 4..=7,
+                depth: 69
+            })
+        );
+    }
+
+    #[test]
+    fn test_deviations() {
+        let depths = [10, 12, 10, 12, 40, 12, 10, 12];
+        let depths = depths.iter().map(|&d| Ok::<_, ()>(d));
+        let anomalies = detect_anomalies(depths, 4, 3.0).unwrap();
+        assert_eq!(anomalies.deviations.len(), 1);
+        let deviation = &anomalies.deviations[0];
+        assert_eq!((deviation.index, deviation.depth), (4, 40));
+        assert_eq!(deviation.readings, 0..=3);
+        assert_eq!((deviation.mean, deviation.std_dev), (11.0, 1.0));
+    }
+
+    #[test]
+    fn test_sweep_shorter_than_window() {
+        let anomalies = detect_anomalies(read_depths("1\n2\n".as_bytes()), 3, 3.0).unwrap();
+        assert_eq!(anomalies, Anomalies::default());
+    }
+}
@@ -0,0 +1,303 @@
+use aoc_core::parse::parse_token;
+use aoc_core::Solution;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::io::{self, BufRead};
+
+pub mod anomaly;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        input.lines().map(parse_token).collect()
+    }
+
+    fn part1(&self, depths: &Self::Input) -> impl Display {
+        depth_increases(depths, 1)
+    }
+
+    fn part2(&self, depths: &Self::Input) -> impl Display {
+        // sliding window
+        depth_increases(depths, 3)
+    }
+}
+
+fn depth_increases(depths: &[usize], window: usize) -> usize {
+    let depths = depths.iter().map(|&depth| Ok::<_, Infallible>(depth));
+    match count_trends(depths, window) {
+        Ok(counts) => counts.increases,
+        Err(never) => match never {},
+    }
+}
+
+/// How the sum of a window of readings compares to the window before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increase,
+    Decrease,
+    Plateau,
+}
+
+/// The sum of the readings in a window, and how it compares to the window before, if there was
+/// one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowSum {
+    /// Number of readings in the window.
+    pub size: usize,
+    /// Index of the first reading in the window.
+    pub start: usize,
+    pub sum: usize,
+    pub trend: Option<Trend>,
+}
+
+/// Sums each window of `size` readings in a row, one reading at a time, keeping only the
+/// readings in the current window.
+#[derive(Debug, Clone)]
+pub struct SlidingWindow {
+    size: usize,
+    readings: VecDeque<usize>,
+    sum: usize,
+    next_start: usize,
+    prev_sum: Option<usize>,
+}
+
+impl SlidingWindow {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "windows need at least one reading");
+        SlidingWindow {
+            size,
+            readings: VecDeque::with_capacity(size),
+            sum: 0,
+            next_start: 0,
+            prev_sum: None,
+        }
+    }
+
+    /// Adds the next reading, returning the window it completes, if it fills one.
+    pub fn push(&mut self, depth: usize) -> Option<WindowSum> {
+        self.readings.push_back(depth);
+        self.sum += depth;
+        if self.readings.len() > self.size {
+            self.sum -= self.readings.pop_front().unwrap();
+        }
+        if self.readings.len() < self.size {
+            return None;
+        }
+
+        let trend = self.prev_sum.map(|prev| match self.sum.cmp(&prev) {
+            Ordering::Greater => Trend::Increase,
+            Ordering::Less => Trend::Decrease,
+            Ordering::Equal => Trend::Plateau,
+        });
+        let window = WindowSum {
+            size: self.size,
+            start: self.next_start,
+            sum: self.sum,
+            trend,
+        };
+        self.prev_sum = Some(self.sum);
+        self.next_start += 1;
+        Some(window)
+    }
+}
+
+/// Iterator adapter turning readings into the sum of each window of them as they come in, so
+/// sweeps of any length can be checked without holding onto them. Readings can fail, e.g.
+/// when they're read from a file, and each failure is passed on as it's reached.
+pub struct SonarSweep<I> {
+    depths: I,
+    windows: Vec<SlidingWindow>,
+    /// Windows completed by the last reading that haven't been handed out yet.
+    pending: VecDeque<WindowSum>,
+}
+
+impl<I> SonarSweep<I> {
+    pub fn new(depths: I, window: usize) -> Self {
+        SonarSweep::with_windows(depths, &[window])
+    }
+
+    /// Sums windows of every size in `sizes` from the one pass over the readings. The windows
+    /// each reading completes come in the order their sizes were given.
+    pub fn with_windows(depths: I, sizes: &[usize]) -> Self {
+        SonarSweep {
+            depths,
+            windows: sizes.iter().map(|&size| SlidingWindow::new(size)).collect(),
+            pending: VecDeque::with_capacity(sizes.len()),
+        }
+    }
+}
+
+impl<I, E> Iterator for SonarSweep<I>
+where
+    I: Iterator<Item = Result<usize, E>>,
+{
+    type Item = Result<WindowSum, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(window) = self.pending.pop_front() {
+                return Some(Ok(window));
+            }
+            match self.depths.next()? {
+                Ok(depth) => {
+                    let completed = self.windows.iter_mut().filter_map(|w| w.push(depth));
+                    self.pending.extend(completed);
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// How many windows were deeper, shallower or the same depth as the window before.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrendCounts {
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+}
+
+impl TrendCounts {
+    pub fn record(&mut self, window: &WindowSum) {
+        match window.trend {
+            Some(Trend::Increase) => self.increases += 1,
+            Some(Trend::Decrease) => self.decreases += 1,
+            Some(Trend::Plateau) => self.plateaus += 1,
+            None => {}
+        }
+    }
+}
+
+impl Display for TrendCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} increases, {} decreases, {} plateaus",
+            self.increases, self.decreases, self.plateaus
+        )
+    }
+}
+
+/// Counts the trends of every window in a sweep, stopping at the first reading that fails.
+pub fn count_trends<E>(
+    depths: impl Iterator<Item = Result<usize, E>>,
+    window: usize,
+) -> Result<TrendCounts, E> {
+    let mut counts = TrendCounts::default();
+    for window in SonarSweep::new(depths, window) {
+        counts.record(&window?);
+    }
+    Ok(counts)
+}
+
+/// Reads a depth from each line, skipping blank ones, as the lines are needed.
+pub fn read_depths(reader: impl BufRead) -> impl Iterator<Item = io::Result<usize>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(l) if l.trim().is_empty()))
+        .map(|(i, line)| {
+            let line = line?;
+            line.trim().parse().map_err(|e| {
+                let message = format!("line {}: {} `{}`", i + 1, e, line);
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example sweep from the puzzle, in input/test.txt.
+    pub(crate) fn example() -> String {
+        aoc_core::input::read_day_input(1, "test").unwrap()
+    }
+
+    #[test]
+    fn test_count_trends() {
+        let example = example();
+        let counts = count_trends(read_depths(example.as_bytes()), 1).unwrap();
+        assert_eq!(
+            counts,
+            TrendCounts {
+                increases: 7,
+                decreases: 2,
+                plateaus: 0
+            }
+        );
+
+        // 607, 618, 618, 617, 647, 716, 769, 792
+        let counts = count_trends(read_depths(example.as_bytes()), 3).unwrap();
+        assert_eq!(
+            counts,
+            TrendCounts {
+                increases: 5,
+                decreases: 1,
+                plateaus: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_sweep_shorter_than_window() {
+        let sums: Vec<_> = SonarSweep::new(read_depths("1\n2\n".as_bytes()), 3).collect();
+        assert!(sums.is_empty());
+        assert_eq!(depth_increases(&[1, 2], 3), 0);
+    }
+
+    #[test]
+    fn test_sweep_windows() {
+        let sums: Vec<WindowSum> = SonarSweep::new(read_depths("1\n2\n3\n3\n".as_bytes()), 2)
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(
+            sums,
+            [
+                WindowSum {
+                    size: 2,
+                    start: 0,
+                    sum: 3,
+                    trend: None
+                },
+                WindowSum {
+                    size: 2,
+                    start: 1,
+                    sum: 5,
+                    trend: Some(Trend::Increase)
+                },
+                WindowSum {
+                    size: 2,
+                    start: 2,
+                    sum: 6,
+                    trend: Some(Trend::Increase)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sweep_several_windows() {
+        let sums: Vec<(usize, usize)> =
+            SonarSweep::with_windows(read_depths("1\n2\n3\n".as_bytes()), &[2, 1])
+                .map(|w| w.map(|w| (w.size, w.sum)))
+                .collect::<io::Result<_>>()
+                .unwrap();
+        assert_eq!(sums, [(1, 1), (2, 3), (1, 2), (2, 5), (1, 3)]);
+    }
+
+    #[test]
+    fn test_read_depths_error() {
+        let e = count_trends(read_depths("1\n\n2\nthree\n".as_bytes()), 1).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "line 4: invalid digit found in string `three`"
+        );
+    }
+}
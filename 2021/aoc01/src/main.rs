@@ -1,39 +1,114 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc01::anomaly::AnomalyDetector;
+use aoc01::{read_depths, SonarSweep, TrendCounts};
+use aoc_core::cache::InputCache;
+use aoc_core::input::InputSource;
+use clap::Parser;
+use std::collections::HashSet;
+use std::process;
+
+/// Solves day 1, or with --window, streams the sonar sweep and counts how the sum of each
+/// window of readings compares to the one before
+#[derive(Parser)]
+struct Cli {
+    /// Name of a file in input/ without the .txt extension, a path to any file, or - for stdin
+    #[arg(default_value = "input")]
+    input: InputSource,
+
+    /// Number of readings to sum in each window, can be given more than once
+    #[arg(long = "window", short)]
+    windows: Vec<usize>,
+
+    /// Also report the longest descent, the steepest drop and readings far from the rolling
+    /// mean for each window, or for windows of 3 if none are given
+    #[arg(long, short)]
+    anomalies: bool,
+
+    /// How many standard deviations from the rolling mean a reading has to be to report it
+    #[arg(long, short = 'k', default_value_t = 3.0, requires = "anomalies")]
+    sigmas: f64,
+}
 
 fn main() {
-    let filename = "input/input.txt";
-    // Open the file in read-only mode (ignoring errors).
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    // Read the file line by line using the lines() iterator from std::io::BufRead.
-    let depths: Vec<usize> = reader
-        .lines()
-        .enumerate()
-        .map(|(_idx, line)| line.expect("couldn't read string!").parse().unwrap())
-        .collect();
-
-    println!("depth_increases: {}", depth_increases(&depths, 1));
-    println!(
-        "depth_increases with sliding window: {}",
-        depth_increases(&depths, 3)
-    )
+    let mut cli = Cli::parse();
+    if cli.windows.is_empty() {
+        if !cli.anomalies {
+            aoc_core::run_day(1, &aoc01::Day01);
+            return;
+        }
+        cli.windows.push(3);
+    }
+    // the same window twice would only be reported twice
+    let mut seen = HashSet::new();
+    cli.windows.retain(|&size| seen.insert(size));
+    if let Err(e) = sweep(&cli) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
 
-fn depth_increases(depths: &Vec<usize>, window: usize) -> usize {
-    let sliding_window_sums: Vec<usize> = depths
-        .windows(window)
-        .map(|window_of_depths| window_of_depths.iter().sum())
-        .collect();
-
-    let mut curr_depth = sliding_window_sums[0];
-    let mut num_increases: usize = 0;
-    for depth in sliding_window_sums[1..].iter() {
-        if *depth > curr_depth {
-            num_increases += 1;
+// Reads the sweep once, summing every size of window as the readings come in
+fn sweep(cli: &Cli) -> Result<(), String> {
+    if cli.windows.contains(&0) {
+        return Err("windows need at least one reading".to_string());
+    }
+    if cli.sigmas.is_nan() || cli.sigmas < 0.0 {
+        return Err(format!("can't be {} standard deviations away", cli.sigmas));
+    }
+    let (path, reader) = cli
+        .input
+        .open(&InputCache::from_env(), 2021, 1)
+        .map_err(|e| e.to_string())?;
+
+    let mut counts = vec![TrendCounts::default(); cli.windows.len()];
+    let mut detectors: Vec<AnomalyDetector> = if cli.anomalies {
+        (cli.windows.iter())
+            .map(|&size| AnomalyDetector::new(size, cli.sigmas))
+            .collect()
+    } else {
+        vec![]
+    };
+    // the detectors look at every reading, not just the sums of windows of them
+    let depths = read_depths(reader).inspect(|depth| {
+        if let Ok(depth) = depth {
+            for detector in &mut detectors {
+                detector.push(*depth);
+            }
+        }
+    });
+    for sum in SonarSweep::with_windows(depths, &cli.windows) {
+        let sum = sum.map_err(|e| format!("{}: {}", path.display(), e))?;
+        let i = cli
+            .windows
+            .iter()
+            .position(|&size| size == sum.size)
+            .unwrap();
+        counts[i].record(&sum);
+    }
+
+    let mut detectors = detectors.into_iter();
+    for (size, counts) in cli.windows.iter().zip(counts) {
+        println!("window {}: {}", size, counts);
+        let Some(detector) = detectors.next() else {
+            continue;
+        };
+        let anomalies = detector.finish();
+        match anomalies.longest_descent {
+            Some(descent) => println!("  longest descent: {}", descent),
+            None => println!("  longest descent: none"),
+        }
+        match anomalies.steepest_drop {
+            Some(drop) => println!("  steepest drop: {}", drop),
+            None => println!("  steepest drop: none"),
+        }
+        println!(
+            "  {} readings more than {} standard deviations from the {} before",
+            anomalies.deviations.len(),
+            cli.sigmas,
+            size
+        );
+        for deviation in &anomalies.deviations {
+            println!("    {}", deviation);
         }
-        curr_depth = *depth;
     }
-    num_increases
+    Ok(())
 }
@@ -0,0 +1,167 @@
+//! Looking back over a whole dive: how deep it went, where it spent its time, and plotting its
+//! profile.
+
+use crate::navigation::State;
+use aoc_core::image::Rgb;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Write};
+
+/// Summary of a trajectory, starting from the surface at step 0. Each command counts as one
+/// step of time, spent in the band of depths the submarine ends that step in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiveStats {
+    pub max_depth: i64,
+    /// The first step that reached the max depth.
+    pub max_depth_step: usize,
+    /// How deep each band of depths is.
+    pub band: i64,
+    /// Steps spent in each band, keyed by the shallowest depth in it.
+    pub time_in_band: BTreeMap<i64, usize>,
+}
+
+impl DiveStats {
+    pub fn new(band: i64) -> Self {
+        assert!(band > 0, "depth bands need to be at least 1 deep");
+        DiveStats {
+            max_depth: 0,
+            max_depth_step: 0,
+            band,
+            time_in_band: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_trajectory(states: impl IntoIterator<Item = State>, band: i64) -> Self {
+        let mut stats = DiveStats::new(band);
+        for (step, state) in states.into_iter().enumerate() {
+            stats.record(step, &state);
+        }
+        stats
+    }
+
+    pub fn record(&mut self, step: usize, state: &State) {
+        if state.depth > self.max_depth {
+            self.max_depth = state.depth;
+            self.max_depth_step = step;
+        }
+        // the surface at step 0 is where the dive starts, not time spent there
+        if step > 0 {
+            // the band around i64::MIN starts below what an i64 holds, so it's keyed by the
+            // shallowest depth it can
+            let band = state.depth.div_euclid(self.band).saturating_mul(self.band);
+            *self.time_in_band.entry(band).or_default() += 1;
+        }
+    }
+}
+
+impl Display for DiveStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "max depth {} at step {}",
+            self.max_depth, self.max_depth_step
+        )?;
+        for (band, steps) in &self.time_in_band {
+            // the deepest band can end past what an i64 holds
+            let end = (i128::from(band.div_euclid(self.band)) + 1) * i128::from(self.band);
+            write!(f, "\n{}..{}: {} steps", band, end, steps)?;
+        }
+        Ok(())
+    }
+}
+
+/// A band depth that splits dives from the surface down to `max_depth` into at most 10 bands.
+pub fn default_band(max_depth: i64) -> i64 {
+    max_depth.max(0) / 10 + 1
+}
+
+/// Writes an SVG plot of each named trajectory as a polyline, with distance going right and
+/// depth going down. Depths are usually far bigger than distances, so the plot is stretched to
+/// fit rather than drawn to scale.
+pub fn write_svg(out: &mut impl Write, trajectories: &[(&str, Vec<State>)]) -> io::Result<()> {
+    let states = || trajectories.iter().flat_map(|(_, states)| states);
+    let min_distance = states().map(|s| s.distance).min().unwrap_or(0);
+    let max_distance = states().map(|s| s.distance).max().unwrap_or(0);
+    let min_depth = states().map(|s| s.depth).min().unwrap_or(0);
+    let max_depth = states().map(|s| s.depth).max().unwrap_or(0);
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="400" viewBox="{} {} {} {}" preserveAspectRatio="none">"#,
+        min_distance,
+        min_depth,
+        max_distance.abs_diff(min_distance).max(1),
+        max_depth.abs_diff(min_depth).max(1)
+    )?;
+    for (i, (name, states)) in trajectories.iter().enumerate() {
+        let Rgb(r, g, b) = Rgb::distinct(i);
+        let points: Vec<String> = states
+            .iter()
+            .map(|s| format!("{},{}", s.distance, s.depth))
+            .collect();
+        writeln!(
+            out,
+            r#"  <polyline fill="none" stroke="rgb({},{},{})" stroke-width="2" vector-effect="non-scaling-stroke" points="{}"><title>{}</title></polyline>"#,
+            r,
+            g,
+            b,
+            points.join(" "),
+            name
+        )?;
+    }
+    writeln!(out, "</svg>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::navigation::{Aim, NavigationModel};
+    use crate::parse_input;
+    use crate::tests::example;
+
+    #[test]
+    fn test_dive_stats() {
+        let instructions = example();
+        let states = Aim.trajectory(&instructions).map(Result::unwrap);
+        let stats = DiveStats::from_trajectory(states, 50);
+        assert_eq!(stats.max_depth, 60);
+        assert_eq!(stats.max_depth_step, 6);
+        assert_eq!(stats.time_in_band, BTreeMap::from([(0, 5), (50, 1)]));
+        assert_eq!(default_band(stats.max_depth), 7);
+    }
+
+    #[test]
+    fn test_default_band() {
+        for max_depth in [0, 9, 10, 60, 99, 100, 12345, i64::MAX] {
+            let band = default_band(max_depth);
+            assert!(max_depth / band < 10, "{} in bands of {}", max_depth, band);
+        }
+        assert_eq!(default_band(100), 11);
+    }
+
+    #[test]
+    fn test_extreme_depths() {
+        let mut stats = DiveStats::new(10);
+        for (step, depth) in [(1, i64::MAX), (2, i64::MIN)] {
+            let state = State {
+                depth,
+                ..State::default()
+            };
+            stats.record(step, &state);
+        }
+        let stats = stats.to_string();
+        assert!(stats.contains("\n9223372036854775800..9223372036854775810: 1 steps"));
+        assert!(stats.contains("\n-9223372036854775808..-9223372036854775800: 1 steps"));
+    }
+
+    #[test]
+    fn test_write_svg() {
+        let instructions = parse_input("forward 2\ndown 1\nforward 3").unwrap();
+        let states: Vec<State> = Aim.trajectory(&instructions).map(Result::unwrap).collect();
+        let mut out = vec![];
+        write_svg(&mut out, &[("aim", states)]).unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert!(svg.contains(r#"viewBox="0 0 5 3""#));
+        assert!(svg.contains(r#"points="0,0 2,0 2,0 5,3""#));
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_core::parse::{parse_token, split_once};
+use aoc_core::{ParseError, Solution};
+use navigation::{Aim, NavigationModel, Simple};
+use std::fmt::Display;
+
+pub mod dive;
+pub mod navigation;
+pub mod script;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+    /// Only in scripts, the opposite of forward.
+    Back,
+    /// Only in scripts, straight up to the surface, ignoring the magnitude.
+    Surface,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(Direction, i32)>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        let instructions = parse_input(input)?;
+        // every line is a command, so the command that overflows is the line to point at
+        let end = Simple
+            .navigate(&instructions)
+            .and_then(|_| Aim.navigate(&instructions));
+        if let Err(overflow) = end {
+            let line = input.lines().nth(overflow.command).unwrap_or(input);
+            return Err(ParseError::new(line, "the submarine goes too far to count"));
+        }
+        Ok(instructions)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> impl Display {
+        let end = Simple.navigate(instructions);
+        end.expect("parse checks the course doesn't overflow")
+            .product()
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> impl Display {
+        let end = Aim.navigate(instructions);
+        end.expect("parse checks the course doesn't overflow")
+            .product()
+    }
+}
+
+pub fn parse_input(input: &str) -> aoc_core::Result<Vec<(Direction, i32)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (direction, magnitude) = split_once(line, " ")?;
+            let direction: Direction = match direction {
+                "forward" => Direction::Forward,
+                "up" => Direction::Up,
+                "down" => Direction::Down,
+                x => return Err(ParseError::new(x, "invalid direction")),
+            };
+            Ok((direction, parse_token(magnitude)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example course from the puzzle, in input/test.txt.
+    pub(crate) fn example() -> Vec<(Direction, i32)> {
+        let input = aoc_core::input::read_day_input(2, "test").unwrap();
+        parse_input(&input).unwrap()
+    }
+}
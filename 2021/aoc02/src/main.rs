@@ -1,95 +1,103 @@
-use std::error::Error;
+use aoc02::dive::{self, DiveStats};
+use aoc02::navigation::{self, NavigationModel, State};
+use aoc02::script;
+use aoc_core::cache::InputCache;
+use aoc_core::input::InputSource;
+use aoc_core::Solution;
+use clap::Parser;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
 
-#[derive(Eq, PartialEq)]
-enum Direction {
-    Forward,
-    Up,
-    Down,
-}
+/// Solves day 2, or with --model, prints where each model of navigating ends up
+#[derive(Parser)]
+struct Cli {
+    /// Name of a file in input/ without the .txt extension, a path to any file, or - for stdin
+    #[arg(default_value = "input")]
+    input: InputSource,
 
-type ParseResult<T> = Result<T, Box<dyn Error>>;
+    /// simple or aim, optionally followed by +drag=PERCENT and +max-depth=DEPTH, e.g.
+    /// aim+drag=10. Can be given more than once, defaults to aim with --script, --stats or
+    /// --svg
+    #[arg(long = "model", short)]
+    models: Vec<String>,
 
-fn main() {
-    let filename = "input/input.txt";
-    let result: ParseResult<Vec<(Direction, i32)>> = parse_input_file(filename);
-    match result {
-        Err(e) => {
-            println!("Error parsing input file: {}", e);
-        }
+    /// Read the input as a script, with comments, repeat blocks, macros, back and surface
+    #[arg(long)]
+    script: bool,
 
-        Ok(instructions) => {
-            // println!("{:?}", instructions);
+    /// Also print the max depth, the step it was reached and the time spent at each depth
+    #[arg(long, short)]
+    stats: bool,
 
-            // Part 1
-            let distance = count_distance(&instructions);
-            let depth = count_depth(&instructions);
-            println!("distance {}", distance);
-            println!("depth {}", depth);
-            println!("product {}", distance * depth);
+    /// How deep each band of depths is in the stats, by default just over a tenth of the max
+    /// depth, for at most 10 bands
+    #[arg(long, requires = "stats", value_parser = clap::value_parser!(i64).range(1..))]
+    band: Option<i64>,
 
-            // Part 2
-            let (distance, depth) = count_with_aim(&instructions);
-            println!("distance {}", distance);
-            println!("depth {}", depth);
-            println!("product {}", distance * depth);
-        }
-    }
+    /// Plot the dive profile of each model to an SVG file
+    #[arg(long)]
+    svg: Option<PathBuf>,
 }
 
-fn parse_input_file(filename: &str) -> ParseResult<Vec<(Direction, i32)>> {
-    // Open the file in read-only mode (ignoring errors).
-    let file = File::open(filename)?;
-    let reader = BufReader::new(file);
-
-    // Read the file line by line using the lines() iterator from std::io::BufRead.
-    reader
-        .lines()
-        .enumerate()
-        .map(|(_, line_res)| {
-            let line = line_res?; // `line` needs to live till end of the closure
-            let tokens: Vec<&str> = line.split(" ").collect();
-            let direction: Direction = match tokens[0] {
-                "forward" => Direction::Forward,
-                "up" => Direction::Up,
-                "down" => Direction::Down,
-                x => return Err(format!("invalid direction: {}", x).into()),
-            };
-            let magnitude = tokens[1].parse()?;
-            Ok((direction, magnitude))
-        })
-        .collect()
-}
-
-fn count_distance(instructions: &Vec<(Direction, i32)>) -> i32 {
-    instructions
-        .iter()
-        .filter(|(dir, _)| dir == &Direction::Forward)
-        .map(|(_, mag)| mag)
-        .sum()
+fn main() {
+    let mut cli = Cli::parse();
+    if cli.models.is_empty() {
+        if !cli.script && !cli.stats && cli.svg.is_none() {
+            aoc_core::run_day(2, &aoc02::Day02);
+            return;
+        }
+        cli.models.push("aim".to_string());
+    }
+    if let Err(e) = compare_models(&cli) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
 
-fn count_depth(instructions: &Vec<(Direction, i32)>) -> i32 {
-    instructions
+fn compare_models(cli: &Cli) -> Result<(), String> {
+    let models = cli
+        .models
         .iter()
-        .map(|(dir, mag)| match dir {
-            Direction::Forward => 0,
-            Direction::Up => -mag,
-            Direction::Down => *mag,
-        })
-        .sum()
-}
+        .map(|name| navigation::by_name(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (path, input) = cli
+        .input
+        .read(&InputCache::from_env(), 2021, 2)
+        .map_err(|e| e.to_string())?;
+    let instructions = if cli.script {
+        script::parse(&input).map(|script| script.commands())
+    } else {
+        aoc02::Day02.parse(&input)
+    };
+    let instructions = instructions.map_err(|e| e.locate(&input).with_file(&path).to_string())?;
 
-fn count_with_aim(instructions: &Vec<(Direction, i32)>) -> (i32, i32) {
-    let (distance, depth, _mag) = instructions.iter().fold(
-        (0, 0, 0),
-        |(curr_distance, curr_depth, curr_aim), (dir, mag)| match dir {
-            Direction::Down => (curr_distance, curr_depth, curr_aim + mag),
-            Direction::Up => (curr_distance, curr_depth, curr_aim - mag),
-            Direction::Forward => (curr_distance + mag, curr_depth + (curr_aim * mag), curr_aim),
-        },
-    );
+    let mut trajectories: Vec<(&str, Vec<State>)> = vec![];
+    for (name, model) in cli.models.iter().zip(&models) {
+        let states = model
+            .trajectory(&instructions)
+            .collect::<Result<Vec<State>, _>>()
+            .map_err(|e| format!("{}: {}: {}", path.display(), name, e))?;
+        let end = states.last().expect("trajectories start at the surface");
+        println!("{}: {}", name, end);
+        if cli.stats {
+            let max_depth = states.iter().map(|s| s.depth).max().unwrap_or(0);
+            let band = cli.band.unwrap_or_else(|| dive::default_band(max_depth));
+            let stats = DiveStats::from_trajectory(states.iter().copied(), band);
+            for line in stats.to_string().lines() {
+                println!("  {}", line);
+            }
+        }
+        trajectories.push((name, states));
+    }
 
-    (distance, depth)
+    if let Some(svg) = &cli.svg {
+        let with_path = |e: std::io::Error| format!("{}: {}", svg.display(), e);
+        let mut out = BufWriter::new(File::create(svg).map_err(with_path)?);
+        dive::write_svg(&mut out, &trajectories)
+            .and_then(|_| out.flush())
+            .map_err(with_path)?;
+    }
+    Ok(())
 }
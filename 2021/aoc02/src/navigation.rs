@@ -0,0 +1,350 @@
+//! Ways of reading the same list of commands, since what `down` means depends on who's asked.
+//!
+//! A [`NavigationModel`] moves a [`State`] one command at a time. The models from the puzzle are
+//! [`Simple`] and [`Aim`], and [`Drag`] and [`MaxDepth`] wrap any model to change how it moves.
+//! [`by_name`] builds one from a name like `aim+drag=10+max-depth=500`.
+//!
+//! Diving along the aim multiplies magnitudes together, so even a short course can go further
+//! than an `i64` counts. Models step with checked arithmetic, and a course that overflows is an
+//! [`Overflow`] error rather than a panic or a wrapped answer.
+
+use crate::Direction;
+use std::fmt::{self, Display, Formatter};
+use std::iter;
+
+/// Where the submarine is, and for models that use it, where it's pointing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    pub distance: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl State {
+    /// What the puzzle asks for, the distance times the depth.
+    pub fn product(&self) -> i128 {
+        i128::from(self.distance) * i128::from(self.depth)
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "distance {}, depth {}, aim {} ({})",
+            self.distance,
+            self.depth,
+            self.aim,
+            self.product()
+        )
+    }
+}
+
+/// A command that took the submarine further than a [`State`] can count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    /// Index of the command in the instructions.
+    pub command: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "command {} takes the submarine further than a 64-bit integer counts",
+            self.command + 1
+        )
+    }
+}
+
+pub trait NavigationModel {
+    /// Moves the submarine for one command, or `None` if it goes further than a state counts.
+    fn step(&self, state: State, direction: &Direction, magnitude: i32) -> Option<State>;
+
+    /// Where the submarine ends up after every command, starting from the surface.
+    fn navigate(&self, instructions: &[(Direction, i32)]) -> Result<State, Overflow> {
+        let mut state = State::default();
+        for (i, (direction, magnitude)) in instructions.iter().enumerate() {
+            state = (self.step(state, direction, *magnitude)).ok_or(Overflow { command: i })?;
+        }
+        Ok(state)
+    }
+
+    /// Every state on the way, starting at the surface, then after each command in turn. Stops
+    /// after the first command that overflows.
+    fn trajectory<'a>(
+        &'a self,
+        instructions: &'a [(Direction, i32)],
+    ) -> impl Iterator<Item = Result<State, Overflow>> + 'a
+    where
+        Self: Sized,
+    {
+        let steps = instructions.iter().enumerate().scan(
+            Some(State::default()),
+            move |state, (i, (direction, magnitude))| {
+                *state = self.step((*state)?, direction, *magnitude);
+                Some(state.ok_or(Overflow { command: i }))
+            },
+        );
+        iter::once(Ok(State::default())).chain(steps)
+    }
+}
+
+impl<M: NavigationModel + ?Sized> NavigationModel for Box<M> {
+    fn step(&self, state: State, direction: &Direction, magnitude: i32) -> Option<State> {
+        (**self).step(state, direction, magnitude)
+    }
+}
+
+/// Part 1, where `up` and `down` change the depth directly.
+#[derive(Debug, Clone, Copy)]
+pub struct Simple;
+
+impl NavigationModel for Simple {
+    fn step(&self, mut state: State, direction: &Direction, magnitude: i32) -> Option<State> {
+        let magnitude = i64::from(magnitude);
+        match direction {
+            Direction::Forward => state.distance = state.distance.checked_add(magnitude)?,
+            Direction::Up => state.depth = state.depth.checked_sub(magnitude)?,
+            Direction::Down => state.depth = state.depth.checked_add(magnitude)?,
+            Direction::Back => state.distance = state.distance.checked_sub(magnitude)?,
+            Direction::Surface => state.depth = 0,
+        }
+        Some(state)
+    }
+}
+
+/// Part 2, where `up` and `down` change the aim, and going forward dives along it. Going back
+/// moves against the aim the submarine has now, which isn't necessarily the one it dove with,
+/// so it stops at the surface rather than flying out of the water. Surfacing levels out too.
+#[derive(Debug, Clone, Copy)]
+pub struct Aim;
+
+impl NavigationModel for Aim {
+    fn step(&self, mut state: State, direction: &Direction, magnitude: i32) -> Option<State> {
+        let magnitude = i64::from(magnitude);
+        match direction {
+            Direction::Forward => {
+                state.distance = state.distance.checked_add(magnitude)?;
+                state.depth = state.depth.checked_add(state.aim.checked_mul(magnitude)?)?;
+            }
+            Direction::Back => {
+                state.distance = state.distance.checked_sub(magnitude)?;
+                let rise = state.aim.checked_mul(magnitude)?;
+                state.depth = state.depth.checked_sub(rise)?.max(0);
+            }
+            Direction::Up => state.aim = state.aim.checked_sub(magnitude)?,
+            Direction::Down => state.aim = state.aim.checked_add(magnitude)?,
+            Direction::Surface => {
+                state.depth = 0;
+                state.aim = 0;
+            }
+        }
+        Some(state)
+    }
+}
+
+/// Loses a percent of every move forward or back to the water, rounding down what's left.
+#[derive(Debug, Clone, Copy)]
+pub struct Drag<M> {
+    pub model: M,
+    percent: i64,
+}
+
+impl<M> Drag<M> {
+    /// Drag of `percent`, from 0 to 100.
+    pub fn new(model: M, percent: i64) -> Result<Self, String> {
+        if !(0..=100).contains(&percent) {
+            return Err(format!("drag is a percent, got {}", percent));
+        }
+        Ok(Drag { model, percent })
+    }
+
+    pub fn percent(&self) -> i64 {
+        self.percent
+    }
+}
+
+impl<M: NavigationModel> NavigationModel for Drag<M> {
+    fn step(&self, state: State, direction: &Direction, magnitude: i32) -> Option<State> {
+        let magnitude = match direction {
+            Direction::Forward | Direction::Back => {
+                // in i64 so big moves don't overflow, what's left always fits back in an i32
+                let left = i64::from(magnitude) * (100 - self.percent) / 100;
+                i32::try_from(left).expect("Drag::new checks the percent")
+            }
+            _ => magnitude,
+        };
+        self.model.step(state, direction, magnitude)
+    }
+}
+
+/// Keeps the submarine between the surface and a max depth, however hard it dives.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxDepth<M> {
+    pub model: M,
+    max_depth: i64,
+}
+
+impl<M> MaxDepth<M> {
+    /// Max depth of `max_depth`, which can't be above the surface.
+    pub fn new(model: M, max_depth: i64) -> Result<Self, String> {
+        if max_depth < 0 {
+            let e = format!("max depth can't be above the surface, got {}", max_depth);
+            return Err(e);
+        }
+        Ok(MaxDepth { model, max_depth })
+    }
+
+    pub fn max_depth(&self) -> i64 {
+        self.max_depth
+    }
+}
+
+impl<M: NavigationModel> NavigationModel for MaxDepth<M> {
+    fn step(&self, state: State, direction: &Direction, magnitude: i32) -> Option<State> {
+        let mut state = self.model.step(state, direction, magnitude)?;
+        state.depth = state.depth.clamp(0, self.max_depth);
+        Some(state)
+    }
+}
+
+/// Builds a model from its name, `simple` or `aim`, followed by any of `+drag=PERCENT` and
+/// `+max-depth=DEPTH`, applied in order.
+pub fn by_name(name: &str) -> Result<Box<dyn NavigationModel>, String> {
+    let mut parts = name.split('+');
+    let mut model: Box<dyn NavigationModel> = match parts.next() {
+        Some("simple") => Box::new(Simple),
+        Some("aim") => Box::new(Aim),
+        _ => return Err(format!("unknown model `{}`, expected simple or aim", name)),
+    };
+    for part in parts {
+        let (modifier, value) = part
+            .split_once('=')
+            .ok_or_else(|| format!("expected `{}=VALUE` in model `{}`", part, name))?;
+        let value: i64 = value
+            .parse()
+            .map_err(|e| format!("{} `{}` in model `{}`", e, value, name))?;
+        model = match modifier {
+            "drag" => Box::new(Drag::new(model, value)?),
+            "max-depth" => Box::new(MaxDepth::new(model, value)?),
+            _ => {
+                let e = format!(
+                    "unknown modifier `{}`, expected drag or max-depth",
+                    modifier
+                );
+                return Err(e);
+            }
+        };
+    }
+    Ok(model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::example;
+
+    fn navigate(name: &str) -> State {
+        by_name(name).unwrap().navigate(&example()).unwrap()
+    }
+
+    #[test]
+    fn test_models() {
+        assert_eq!(navigate("simple").product(), 150);
+        assert_eq!(navigate("aim").product(), 900);
+        assert_eq!(
+            navigate("aim+drag=50"),
+            State {
+                distance: 7,
+                depth: 30,
+                aim: 10
+            }
+        );
+        assert_eq!(navigate("aim+max-depth=30").depth, 30);
+        assert_eq!(navigate("simple+max-depth=5").depth, 5);
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let instructions = example();
+        let depths: Vec<i64> = (Aim.trajectory(&instructions))
+            .map(|s| s.unwrap().depth)
+            .collect();
+        assert_eq!(depths, [0, 0, 0, 40, 40, 40, 60]);
+        let model = by_name("aim").unwrap();
+        assert_eq!(
+            model.trajectory(&instructions).last(),
+            Some(Ok(navigate("aim")))
+        );
+    }
+
+    #[test]
+    fn test_aim_back_stops_at_the_surface() {
+        let instructions = [
+            (Direction::Down, 2),
+            (Direction::Forward, 5),
+            (Direction::Down, 2),
+            (Direction::Back, 2),
+        ];
+        let depths: Vec<i64> = (Aim.trajectory(&instructions))
+            .map(|s| s.unwrap().depth)
+            .collect();
+        assert_eq!(depths, [0, 0, 10, 10, 2]);
+
+        // steeper on the way back than on the way down, so it'd otherwise come out at -10
+        let instructions = [
+            (Direction::Down, 2),
+            (Direction::Forward, 5),
+            (Direction::Down, 2),
+            (Direction::Back, 5),
+        ];
+        assert_eq!(Aim.navigate(&instructions).unwrap().depth, 0);
+    }
+
+    #[test]
+    fn test_drag_big_moves() {
+        let drag = Drag::new(Simple, 10).unwrap();
+        let state = drag.navigate(&[(Direction::Forward, i32::MAX)]).unwrap();
+        assert_eq!(state.distance, 1_932_735_282);
+    }
+
+    #[test]
+    fn test_beyond_i32() {
+        let instructions = [
+            (Direction::Forward, 2_000_000_000),
+            (Direction::Forward, 2_000_000_000),
+            (Direction::Down, 2_000_000_000),
+        ];
+        let state = Simple.navigate(&instructions).unwrap();
+        assert_eq!(state.distance, 4_000_000_000);
+        assert_eq!(state.product(), 8_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // an aim of about 2^32 takes the depth to about 2^63 on the first move forward
+        let instructions = [
+            (Direction::Down, i32::MAX),
+            (Direction::Down, i32::MAX),
+            (Direction::Forward, i32::MAX),
+            (Direction::Forward, i32::MAX),
+            (Direction::Forward, i32::MAX),
+        ];
+        assert_eq!(Aim.navigate(&instructions), Err(Overflow { command: 3 }));
+        let states: Vec<_> = Aim.trajectory(&instructions).collect();
+        assert_eq!(states.len(), 5);
+        assert_eq!(states[4], Err(Overflow { command: 3 }));
+        assert!(Simple.navigate(&instructions).is_ok());
+    }
+
+    #[test]
+    fn test_by_name_errors() {
+        assert!(by_name("sideways").is_err());
+        assert!(by_name("aim+drag").is_err());
+        assert!(by_name("aim+drag=150").is_err());
+        assert!(by_name("aim+current=3").is_err());
+        assert!(Drag::new(Simple, -100).is_err());
+        assert!(MaxDepth::new(Aim, -1).is_err());
+    }
+}
@@ -0,0 +1,327 @@
+//! Scripts of commands for the submarine, for courses too long to write out one move at a time.
+//!
+//! Every line of the puzzle input is a valid script line, and scripts can also:
+//!
+//! ```text
+//! # comments run to the end of the line
+//! back 3          # the opposite of forward
+//! surface         # straight up to the surface
+//! repeat 4 {      # the block, 4 times over
+//!     forward 2
+//! }
+//! macro dive {    # a named block, only at the top level
+//!     down 5
+//!     forward 10
+//! }
+//! dive            # runs the macro, once it's been defined
+//! ```
+//!
+//! Blocks open at the end of a line and close with a `}` on a line of its own. A script is
+//! parsed into a [`Script`], then expanded into the commands a
+//! [`NavigationModel`](crate::navigation::NavigationModel) steps through.
+
+use crate::Direction;
+use aoc_core::parse::parse_token;
+use aoc_core::ParseError;
+use std::collections::HashMap;
+
+/// How many commands a script may expand to, so a few nested repeats can't use up all memory.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Move(Direction, i32),
+    Repeat(usize, Vec<Statement>),
+    /// Runs the macro with this name.
+    Call(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statement {
+    /// 1-based line the statement starts on.
+    pub line: usize,
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Script {
+    pub macros: HashMap<String, Vec<Statement>>,
+    pub body: Vec<Statement>,
+}
+
+impl Script {
+    /// Every command the script runs, in order, with repeats and macros expanded.
+    pub fn commands(&self) -> Vec<(Direction, i32)> {
+        let mut commands = vec![];
+        self.expand(&self.body, &mut commands);
+        commands
+    }
+
+    fn expand(&self, statements: &[Statement], commands: &mut Vec<(Direction, i32)>) {
+        for statement in statements {
+            match &statement.command {
+                Command::Move(direction, magnitude) => commands.push((*direction, *magnitude)),
+                Command::Repeat(times, body) => {
+                    for _ in 0..*times {
+                        self.expand(body, commands);
+                    }
+                }
+                // the parser only accepts calls to macros it has already seen
+                Command::Call(name) => self.expand(&self.macros[name], commands),
+            }
+        }
+    }
+}
+
+const KEYWORDS: [&str; 7] = [
+    "forward", "up", "down", "back", "surface", "repeat", "macro",
+];
+
+pub fn parse(input: &str) -> aoc_core::Result<Script> {
+    let mut parser = Parser {
+        lines: input.lines().enumerate(),
+        script: Script::default(),
+        macro_lens: HashMap::new(),
+    };
+    let (body, _) = parser.block(None).map_err(|e| e.locate(input))?;
+    parser.script.body = body;
+    Ok(parser.script)
+}
+
+struct Parser<I> {
+    lines: I,
+    script: Script,
+    /// How many commands each macro expands to.
+    macro_lens: HashMap<String, usize>,
+}
+
+impl<'a, I: Iterator<Item = (usize, &'a str)>> Parser<I> {
+    // Parses statements up to the `}` closing the block opened by `open`, or to the end of the
+    // script at the top level, returning them with how many commands they expand to
+    fn block(&mut self, open: Option<&'a str>) -> aoc_core::Result<(Vec<Statement>, usize)> {
+        let mut statements = vec![];
+        let mut len = 0usize;
+        loop {
+            let Some((i, line)) = self.lines.next() else {
+                return match open {
+                    Some(open) => Err(ParseError::new(open, "block is never closed")),
+                    None => Ok((statements, len)),
+                };
+            };
+            let code = line.split('#').next().unwrap_or_default().trim();
+            let mut tokens = code.split_whitespace();
+            let Some(first) = tokens.next() else {
+                continue;
+            };
+            if first == "}" {
+                expect_end(tokens)?;
+                return match open {
+                    Some(_) => Ok((statements, len)),
+                    None => Err(ParseError::new(first, "no block to close")),
+                };
+            }
+
+            let (command, command_len) = match first {
+                "forward" | "up" | "down" | "back" => {
+                    let direction = match first {
+                        "forward" => Direction::Forward,
+                        "up" => Direction::Up,
+                        "down" => Direction::Down,
+                        _ => Direction::Back,
+                    };
+                    let magnitude = tokens
+                        .next()
+                        .ok_or_else(|| ParseError::missing(code, "a number"))?;
+                    (Command::Move(direction, parse_token(magnitude)?), 1)
+                }
+                "surface" => (Command::Move(Direction::Surface, 0), 1),
+                "repeat" => {
+                    let times = tokens
+                        .next()
+                        .ok_or_else(|| ParseError::missing(code, "a count"))?;
+                    let times: usize = parse_token(times)?;
+                    expect_open(&mut tokens, code)?;
+                    let (body, body_len) = self.block(Some(first))?;
+                    (Command::Repeat(times, body), times.saturating_mul(body_len))
+                }
+                "macro" => {
+                    if open.is_some() {
+                        let e = "macros can only be defined at the top level";
+                        return Err(ParseError::new(first, e));
+                    }
+                    let name = tokens
+                        .next()
+                        .ok_or_else(|| ParseError::missing(code, "a name"))?;
+                    self.check_macro_name(name)?;
+                    expect_open(&mut tokens, code)?;
+                    let (body, body_len) = self.block(Some(first))?;
+                    self.script.macros.insert(name.to_string(), body);
+                    self.macro_lens.insert(name.to_string(), body_len);
+                    continue;
+                }
+                name => match self.macro_lens.get(name) {
+                    Some(&macro_len) => (Command::Call(name.to_string()), macro_len),
+                    None => return Err(ParseError::new(name, "unknown command or macro")),
+                },
+            };
+            expect_end(tokens)?;
+
+            len = len.saturating_add(command_len);
+            // only what's run from the top level is ever expanded
+            if open.is_none() && len > MAX_COMMANDS {
+                let e = format!("script expands to more than {} commands", MAX_COMMANDS);
+                return Err(ParseError::new(first, e));
+            }
+            statements.push(Statement {
+                line: i + 1,
+                command,
+            });
+        }
+    }
+
+    fn check_macro_name(&self, name: &str) -> aoc_core::Result<()> {
+        let mut chars = name.chars();
+        let is_identifier = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !is_identifier {
+            Err(ParseError::new(name, "invalid macro name"))
+        } else if KEYWORDS.contains(&name) {
+            Err(ParseError::new(
+                name,
+                "macros can't be named after a command",
+            ))
+        } else if self.macro_lens.contains_key(name) {
+            Err(ParseError::new(name, "macro is already defined"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn expect_open<'a>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+    code: &'a str,
+) -> aoc_core::Result<()> {
+    match tokens.next() {
+        Some("{") => Ok(()),
+        Some(token) => Err(ParseError::new(token, "expected `{`")),
+        None => Err(ParseError::missing(code, "`{`")),
+    }
+}
+
+fn expect_end<'a>(mut tokens: impl Iterator<Item = &'a str>) -> aoc_core::Result<()> {
+    match tokens.next() {
+        Some(token) => Err(ParseError::new(token, "unexpected")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::navigation::{Aim, NavigationModel, Overflow, Simple, State};
+    use crate::tests::example;
+
+    #[test]
+    fn test_puzzle_input_is_a_script() {
+        let input = aoc_core::input::read_day_input(2, "test").unwrap();
+        assert_eq!(parse(&input).unwrap().commands(), example());
+    }
+
+    #[test]
+    fn test_repeat_and_macros() {
+        let input = "\
+# dive twice, then come back
+macro dive {
+    down 5   # steeper
+    forward 10
+}
+repeat 2 {
+    dive
+}
+
+back 20
+surface
+";
+        let script = parse(input).unwrap();
+        assert_eq!(script.body[0].line, 6);
+        assert_eq!(
+            script.commands(),
+            [
+                (Direction::Down, 5),
+                (Direction::Forward, 10),
+                (Direction::Down, 5),
+                (Direction::Forward, 10),
+                (Direction::Back, 20),
+                (Direction::Surface, 0),
+            ]
+        );
+
+        let commands = script.commands();
+        let depth = Simple.trajectory(&commands).nth(4).unwrap().unwrap().depth;
+        assert_eq!(depth, 10);
+        let before_surfacing = Aim.trajectory(&commands).nth(5).unwrap().unwrap();
+        // backing up at the steeper aim would rise further than the dives went down
+        assert_eq!((before_surfacing.distance, before_surfacing.depth), (0, 0));
+        assert_eq!(before_surfacing.aim, 10);
+        assert_eq!(Aim.navigate(&commands), Ok(State::default()));
+    }
+
+    #[test]
+    fn test_beyond_i32() {
+        let script = parse("down 100000\nrepeat 1000 {\n    forward 100000\n}").unwrap();
+        let end = Aim.navigate(&script.commands()).unwrap();
+        assert_eq!(
+            end,
+            State {
+                distance: 100_000_000,
+                depth: 10_000_000_000_000,
+                aim: 100_000
+            }
+        );
+        assert_eq!(end.product(), 1_000_000_000_000_000_000_000);
+
+        // far enough to overflow an i64 too, which is an error rather than a wrapped answer
+        let script = parse("repeat 2 {\n  down 2147483647\n}\nrepeat 3 {\n  forward 2147483647\n}");
+        let commands = script.unwrap().commands();
+        assert_eq!(Aim.navigate(&commands), Err(Overflow { command: 3 }));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input: &str| parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 1\nsideways 2"),
+            "line 2, column 1: unknown command or macro `sideways`"
+        );
+        assert_eq!(
+            error("forward 1\nrepeat 3 {\n  forward 1"),
+            "line 2, column 1: block is never closed `repeat`"
+        );
+        assert_eq!(error("}"), "line 1, column 1: no block to close `}`");
+        assert_eq!(
+            error("dive\nmacro dive {\n}"),
+            "line 1, column 1: unknown command or macro `dive`"
+        );
+        assert_eq!(
+            error("macro dive {\n  dive\n}"),
+            "line 2, column 3: unknown command or macro `dive`"
+        );
+        assert_eq!(
+            error("repeat 2 {\n  macro dive {\n  }\n}"),
+            "line 2, column 3: macros can only be defined at the top level `macro`"
+        );
+        assert_eq!(
+            error("macro up {\n}"),
+            "line 1, column 7: macros can't be named after a command `up`"
+        );
+        assert_eq!(error("forward"), "line 1, column 8: expected a number");
+        assert_eq!(error("surface 3"), "line 1, column 9: unexpected `3`");
+        assert_eq!(
+            error("repeat 10000 {\n  repeat 10000 {\n    forward 1\n  }\n}"),
+            "line 1, column 1: script expands to more than 10000000 commands `repeat`"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_core::parse::char_tokens;
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, binary_numbers: &Self::Input) -> impl Display {
+        let gamma_str = calculate_gamma_rate_str(binary_numbers);
+        let gamma_val = binary_str_to_num(&gamma_str);
+
+        let epsilon_str = binary_complement(&gamma_str);
+        let epsilon_val = binary_str_to_num(&epsilon_str);
+
+        // power consumption
+        gamma_val * epsilon_val
+    }
+
+    fn part2(&self, binary_numbers: &Self::Input) -> impl Display {
+        let oxygen_generator_rating = calculate_oxygen_generator_rating(binary_numbers);
+        let co2_scrubber_rating = calculate_co2_scrubber_rating(binary_numbers);
+
+        // life support rating
+        oxygen_generator_rating * co2_scrubber_rating
+    }
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<String>> {
+    let first = input
+        .lines()
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::missing(input, "binary numbers"))?;
+    input
+        .lines()
+        .map(|line| {
+            if let Some(c) = char_tokens(line).find(|&c| c != "0" && c != "1") {
+                return Err(ParseError::new(c, "expected a binary digit"));
+            }
+            // every bit position is compared across all the numbers
+            if line.len() != first.len() {
+                let e = format!("expected {} bits, like the first number", first.len());
+                return Err(ParseError::new(line, e));
+            }
+            Ok(line.to_string())
+        })
+        .collect()
+}
+
+fn calculate_gamma_rate_str(binary_numbers: &[String]) -> String {
+    let binary_nums_as_chars: Vec<Vec<char>> =
+        binary_numbers.iter().map(|n| n.chars().collect()).collect();
+
+    (0..binary_numbers[0].len())
+        .map(|i| most_common_bit(&binary_nums_as_chars, i))
+        .collect::<String>()
+}
+
+fn calculate_oxygen_generator_rating(binary_numbers: &[String]) -> isize {
+    filter_binary_nums_by_criteria(binary_numbers, most_common_bit)
+}
+
+fn calculate_co2_scrubber_rating(binary_numbers: &[String]) -> isize {
+    filter_binary_nums_by_criteria(binary_numbers, least_common_bit)
+}
+
+fn filter_binary_nums_by_criteria(binary_numbers: &[String], bit_criteria: BitCriteria) -> isize {
+    let mut binary_nums_as_chars: Vec<Vec<char>> =
+        binary_numbers.iter().map(|n| n.chars().collect()).collect();
+
+    let mut bit_position = 0;
+    while binary_nums_as_chars.len() != 1 {
+        let column_bit_value = bit_criteria(&binary_nums_as_chars, bit_position);
+
+        // filter binary_numbers by only those that have the most common bit in this position
+        let filtered_nums: Vec<Vec<char>> = binary_nums_as_chars
+            .into_iter()
+            .filter(|bn| bn[bit_position] == column_bit_value)
+            .collect();
+
+        binary_nums_as_chars = filtered_nums;
+        bit_position += 1;
+    }
+
+    let rating_str: String = binary_nums_as_chars
+        .first()
+        .expect("expected to find last binary number")
+        .iter()
+        .collect();
+
+    binary_str_to_num(&rating_str)
+}
+
+type BitCriteria = fn(binary_nums_as_chars: &[Vec<char>], bit_position: usize) -> char;
+
+fn most_common_bit(binary_nums_as_chars: &[Vec<char>], i: usize) -> char {
+    let (num_zeros, num_ones) = count_digits(binary_nums_as_chars, i);
+    if num_zeros > num_ones {
+        '0'
+    } else {
+        '1'
+    }
+}
+
+fn least_common_bit(binary_nums_as_chars: &[Vec<char>], i: usize) -> char {
+    let most_common_bit = most_common_bit(binary_nums_as_chars, i);
+    flip(most_common_bit)
+}
+
+fn count_digits(binary_nums_as_chars: &[Vec<char>], i: usize) -> (usize, usize) {
+    binary_nums_as_chars
+        .iter()
+        .map(move |bn| bn[i])
+        .fold((0, 0), |(num_zeros, num_ones), n| {
+            if n == '0' {
+                (num_zeros + 1, num_ones)
+            } else {
+                (num_zeros, num_ones + 1)
+            }
+        })
+}
+
+fn binary_str_to_num(binary_str: &str) -> isize {
+    isize::from_str_radix(binary_str, 2).unwrap()
+}
+
+fn binary_complement(binary_str: &str) -> String {
+    String::from(binary_str).chars().map(flip).collect()
+}
+
+fn flip(bit: char) -> char {
+    if bit == '0' {
+        '1'
+    } else {
+        '0'
+    }
+}
@@ -0,0 +1,162 @@
+use aoc_core::input::parse_comma_separated;
+use aoc_core::parse::parse_token;
+use aoc_core::{ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+type BingoIndex = HashMap<usize, (usize, usize)>;
+type BingoBoard = [[(usize, bool); 5]; 5];
+type BingoGame = (Vec<usize>, Vec<(BingoIndex, BingoBoard)>);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = BingoGame;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (drawn_numbers, boards): &Self::Input) -> impl Display {
+        let (final_drawn_number, winning_board) =
+            find_first_winning_board(drawn_numbers, boards.clone())
+                .expect("no winning board found");
+
+        score(final_drawn_number, &winning_board)
+    }
+
+    fn part2(&self, (drawn_numbers, boards): &Self::Input) -> impl Display {
+        let (final_drawn_number, winning_board) =
+            find_last_winning_board(drawn_numbers, boards.clone())
+                .expect("no last winning board found");
+
+        score(final_drawn_number, &winning_board)
+    }
+}
+
+fn find_first_winning_board(
+    drawn_numbers: &[usize],
+    mut boards: Vec<(BingoIndex, BingoBoard)>,
+) -> Option<(usize, BingoBoard)> {
+    for &n in drawn_numbers {
+        boards
+            .iter_mut()
+            .for_each(|(bidx, board)| mark_board(bidx, board, n));
+
+        let maybe_winning_board = boards.iter().find(|&(_, board)| has_bingo(board));
+
+        if let Some(&(_, winning_board)) = maybe_winning_board {
+            return Some((n, winning_board));
+        }
+    }
+
+    None
+}
+
+fn find_last_winning_board(
+    drawn_numbers: &[usize],
+    mut boards: Vec<(BingoIndex, BingoBoard)>,
+) -> Option<(usize, BingoBoard)> {
+    fn next_winning_board_pos(boards: &[(BingoIndex, BingoBoard)]) -> Option<usize> {
+        boards
+            .iter()
+            .enumerate()
+            .find(|(_, (_, board))| has_bingo(board))
+            .map(|(i, _)| i)
+    }
+
+    for &n in drawn_numbers {
+        boards
+            .iter_mut()
+            .for_each(|(bidx, board)| mark_board(bidx, board, n));
+
+        let mut maybe_winning_board_pos = next_winning_board_pos(&boards);
+
+        while let Some(idx) = maybe_winning_board_pos {
+            let (_, winning_board) = boards.remove(idx);
+            if boards.is_empty() {
+                return Some((n, winning_board));
+            }
+
+            maybe_winning_board_pos = next_winning_board_pos(&boards);
+        }
+    }
+
+    None
+}
+
+fn mark_board(bidx: &BingoIndex, board: &mut BingoBoard, n: usize) {
+    if let Some(&(x, y)) = bidx.get(&n) {
+        board[x][y].1 = true;
+    }
+}
+
+fn has_bingo(board: &BingoBoard) -> bool {
+    // check all rows
+    if board
+        .iter()
+        .any(|row| row.iter().all(|&(_, marked)| marked))
+    {
+        return true;
+    }
+
+    // check all columns
+    (0..5).any(|j| board.iter().all(|row| row[j].1))
+}
+
+fn sum_of_unmarked(board: &BingoBoard) -> usize {
+    board
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|&(_, marked)| !*marked)
+                .map(|&(n, _)| n)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<BingoGame> {
+    let drawn_numbers = parse_comma_separated(input)?;
+
+    let board_lines: Vec<&str> = input.lines().skip(1).filter(|l| !l.is_empty()).collect();
+    let boards = board_lines
+        .chunks(5)
+        .map(parse_board)
+        .collect::<aoc_core::Result<Vec<_>>>()?;
+    if boards.is_empty() {
+        return Err(ParseError::missing(input, "boards after the drawn numbers"));
+    }
+
+    Ok((drawn_numbers, boards))
+}
+
+fn parse_board(lines: &[&str]) -> aoc_core::Result<(BingoIndex, BingoBoard)> {
+    if lines.len() < 5 {
+        return Err(ParseError::missing(
+            lines[lines.len() - 1],
+            "5 rows in board",
+        ));
+    }
+
+    let mut board: BingoBoard = [[(0, false); 5]; 5];
+    let mut bidx: BingoIndex = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let numbers: Vec<&str> = line.split_whitespace().collect();
+        if numbers.len() != 5 {
+            return Err(ParseError::new(line, "expected 5 numbers in row"));
+        }
+        for (j, n) in numbers.into_iter().enumerate() {
+            let parsed = parse_token(n)?;
+            if bidx.insert(parsed, (i, j)).is_some() {
+                return Err(ParseError::new(n, "number is already on the board"));
+            }
+            board[i][j].0 = parsed
+        }
+    }
+    Ok((bidx, board))
+}
+
+fn score(final_drawn_number: usize, winning_board: &BingoBoard) -> usize {
+    final_drawn_number * sum_of_unmarked(winning_board)
+}
@@ -0,0 +1,309 @@
+use aoc_core::geom::{Point2, Vec2};
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::image::{self, ExportImage, Rgb};
+use aoc_core::parse::{parse_token, split_once};
+use aoc_core::{ParseError, Part, Solution};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use tracing::trace;
+
+#[derive(Debug, Clone)]
+pub struct LineSegment(Point2, Point2);
+
+/*
+Note to self: If I were to go back and do this again, I'd pursue the "intersection of line segments" strategy rather than "evaluate coverage over each point" strategy.
+We know that it's the more efficient method, and the addition of the diagonal lines gave the former strategy a number of edge cases that were tricky to account for.
+*/
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<LineSegment>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, line_segments: &Self::Input) -> impl Display {
+        count_covered_points(&hv_line_segments(line_segments))
+    }
+
+    fn part2(&self, line_segments: &Self::Input) -> impl Display {
+        count_covered_points(line_segments)
+    }
+}
+
+// Points covered by one vent are dark blue, and points where vents overlap go from red up to
+// yellow where the most overlap
+impl ExportImage for Day05 {
+    fn export_image(&self, part: Part, line_segments: &Self::Input, path: &Path) -> io::Result<()> {
+        let coverage = match part {
+            Part::One => coverage(&hv_line_segments(line_segments))?,
+            Part::Two => coverage(line_segments)?,
+        };
+        let most_overlapping = coverage.values().copied().max().unwrap_or(0);
+        image::save(path, &coverage, |&count| match count {
+            0 => Rgb::BLACK,
+            1 => Rgb(0, 0, 128),
+            n => Rgb(255, 0, 0).mix(
+                Rgb(255, 255, 0),
+                (n - 2) as f64 / (most_overlapping.max(3) - 2) as f64,
+            ),
+        })
+    }
+}
+
+fn hv_line_segments(line_segments: &[LineSegment]) -> Vec<LineSegment> {
+    line_segments
+        .iter()
+        .filter(|ls| ls.is_horizontal() || ls.is_vertical())
+        .cloned()
+        .collect()
+}
+
+// How many segments cover each point, with x along the columns and y down the rows. Segments
+// have to be horizontal, vertical or at 45 degrees, so each one can be walked a point at a time,
+// and on the grid, so none of their points are negative.
+fn coverage(segments: &[LineSegment]) -> io::Result<Grid<usize>> {
+    for ls @ LineSegment(start, end) in segments {
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+            let e = format!("vent {} isn't horizontal, vertical or at 45 degrees", ls);
+            return Err(io::Error::other(e));
+        }
+        if [start.x, start.y, end.x, end.y].iter().any(|&n| n < 0) {
+            return Err(io::Error::other(format!("vent {} is off the grid", ls)));
+        }
+    }
+    let to_usize = |n: isize| usize::try_from(n).expect("vents are checked to be on the grid");
+    let max = |axis: fn(&Point2) -> isize| {
+        segments
+            .iter()
+            .flat_map(|ls| [axis(&ls.0), axis(&ls.1)])
+            .max()
+            .map_or(0, |n| to_usize(n) + 1)
+    };
+    let mut coverage = Grid::new(max(|p| p.y), max(|p| p.x), 0);
+
+    for LineSegment(start, end) in segments {
+        let direction = Vec2::new((end.x - start.x).signum(), (end.y - start.y).signum());
+        let mut p = *start;
+        loop {
+            coverage[Pos::new(to_usize(p.y), to_usize(p.x))] += 1;
+            if p == *end {
+                break;
+            }
+            p += direction;
+        }
+    }
+    Ok(coverage)
+}
+
+// Simpler to implement, but less efficient due to querying every discrete point.
+fn count_covered_points(segments: &[LineSegment]) -> usize {
+    // part 1 leaves out the diagonals, which can leave no segments at all
+    let max_x = segments
+        .iter()
+        .flat_map(|ls| vec![ls.0.x, ls.1.x])
+        .max()
+        .unwrap_or(0);
+    let max_y = segments
+        .iter()
+        .flat_map(|ls| vec![ls.0.y, ls.1.y])
+        .max()
+        .unwrap_or(0);
+
+    let mut num_covered_points = 0;
+    for x in 0..=max_x {
+        for y in 0..=max_y {
+            let p = Point2::new(x, y);
+            trace!(?p, "checking point");
+            let covering_segments: Vec<_> = segments.iter().filter(|&ls| ls.contains(&p)).collect();
+            if covering_segments.len() > 1 {
+                trace!(?covering_segments, ?p, "covered");
+                num_covered_points += 1;
+            }
+        }
+    }
+    num_covered_points
+}
+
+// More efficient, but more complicated from line segment intersection logic.
+#[allow(dead_code)]
+fn count_intersecting_points(segments: &[LineSegment]) -> usize {
+    let mut intersecting_points: HashSet<Point2> = HashSet::new();
+    for i in 0..segments.len() {
+        for j in (i + 1)..segments.len() {
+            segments[i]
+                .intersects(&segments[j])
+                .into_iter()
+                .for_each(|p| {
+                    intersecting_points.insert(p);
+                })
+        }
+    }
+    trace!(?intersecting_points);
+    intersecting_points.len()
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<LineSegment>> {
+    if input.trim().is_empty() {
+        return Err(ParseError::missing(input, "lines of vents"));
+    }
+    input
+        .lines()
+        .map(|line| {
+            let (start, end) = split_once(line, " -> ")?;
+            Ok(LineSegment(parse_point(start)?, parse_point(end)?))
+        })
+        .collect()
+}
+
+fn parse_point(s: &str) -> aoc_core::Result<Point2> {
+    let (x, y) = split_once(s, ",")?;
+    Ok(Point2::new(parse_token(x)?, parse_token(y)?))
+}
+
+impl LineSegment {
+    fn is_horizontal(&self) -> bool {
+        self.0.y == self.1.y
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.0.x == self.1.x
+    }
+
+    fn contains(&self, p: &Point2) -> bool {
+        let xrange = valid_range(self.0.x, self.1.x);
+        let yrange = valid_range(self.0.y, self.1.y);
+        let within_range = xrange.contains(&p.x) && yrange.contains(&p.y);
+
+        if self.is_horizontal() || self.is_vertical() {
+            within_range
+        } else {
+            trace!(segment = ?self, ?p, "diagonal slope");
+            // check if slope between p and self.0 is the same as self.0 and self.1
+
+            if p == &self.0 || p == &self.1 {
+                return true;
+            }
+            let self_slope = LineSegment::slope(&self.0, &self.1);
+            let point_slope = LineSegment::slope(&self.0, p);
+
+            if let (Some(self_slope), Some(point_slope)) = (self_slope, point_slope) {
+                trace!(?self_slope, ?point_slope);
+                self_slope == point_slope && within_range
+            } else {
+                false
+            }
+        }
+    }
+
+    // Determines points of intersection between this line and another.
+    // Note that now lines are _only_ horizontal or vertical.
+    #[allow(dead_code)]
+    fn intersects(&self, other: &LineSegment) -> Vec<Point2> {
+        if self.is_horizontal() && other.is_horizontal() {
+            if self.0.y == other.0.y {
+                // potentially coinciding
+                let xrange = self.0.x..=self.1.x;
+                if xrange.contains(&other.0.x) || xrange.contains(&other.1.x) {
+                    return (other.0.x..=other.1.x)
+                        .filter(|x| xrange.contains(x))
+                        .map(|x| Point2::new(x, self.0.y))
+                        .collect();
+                }
+            }
+        } else if self.is_vertical() && other.is_vertical() {
+            if self.0.x == other.0.x {
+                // potentially coinciding
+                let yrange = self.0.y..=self.1.y;
+                if yrange.contains(&other.0.y) || yrange.contains(&other.1.y) {
+                    return (other.0.y..=other.1.y)
+                        .filter(|y| yrange.contains(y))
+                        .map(|y| Point2::new(self.0.x, y))
+                        .collect();
+                }
+            }
+        } else {
+            // TODO
+            return vec![];
+        }
+        vec![]
+    }
+
+    fn slope(a: &Point2, b: &Point2) -> Option<(isize, isize)> {
+        let Vec2 {
+            x: diff_x,
+            y: diff_y,
+        } = *b - *a;
+        trace!(diff_x, diff_y);
+
+        let div = diff_y.checked_div(diff_x);
+        let rem = diff_y.checked_rem(diff_x);
+        if let (Some(div), Some(rem)) = (div, rem) {
+            Some((div, rem))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for LineSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let LineSegment(start, end) = self;
+        write!(f, "{},{} -> {},{}", start.x, start.y, end.x, end.y)
+    }
+}
+
+fn valid_range(a: isize, b: isize) -> RangeInclusive<isize> {
+    if a < b {
+        a..=b
+    } else {
+        b..=a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_coverage() {
+        let segments = parse_input(EXAMPLE).unwrap();
+        let coverage = coverage(&segments).unwrap();
+        assert_eq!((coverage.rows(), coverage.cols()), (10, 10));
+        // the middle row of the diagram in the puzzle, `.112313211`
+        assert_eq!(coverage.row(4), [0, 1, 1, 2, 3, 1, 3, 2, 1, 1]);
+
+        let overlapping = coverage.values().filter(|&&n| n > 1).count();
+        assert_eq!(overlapping, count_covered_points(&segments));
+    }
+
+    #[test]
+    fn test_coverage_errors() {
+        let error = |input| coverage(&parse_input(input).unwrap()).unwrap_err();
+        assert_eq!(
+            error("-1,0 -> 2,0").to_string(),
+            "vent -1,0 -> 2,0 is off the grid"
+        );
+        assert_eq!(
+            error("0,0 -> 1,3").to_string(),
+            "vent 0,0 -> 1,3 isn't horizontal, vertical or at 45 degrees"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_core::input::parse_comma_separated;
+use aoc_core::Solution;
+use std::fmt::Display;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<u8>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_comma_separated(input)
+    }
+
+    fn part1(&self, fish: &Self::Input) -> impl Display {
+        count_fish(fish, 80)
+    }
+
+    fn part2(&self, fish: &Self::Input) -> impl Display {
+        count_fish(fish, 256)
+    }
+}
+
+fn count_fish(fish: &[u8], num_days: usize) -> usize {
+    let mut fish_reproduction_schedule: [usize; 9] = [0; 9];
+    fish.iter()
+        .for_each(|days_left| fish_reproduction_schedule[usize::from(*days_left)] += 1);
+
+    (0..num_days).for_each(|_| advance_day(&mut fish_reproduction_schedule));
+
+    fish_reproduction_schedule.iter().sum()
+}
+
+fn advance_day(fish_reproduction_schedule: &mut [usize; 9]) {
+    // save new fish for today
+    let num_new_fish = fish_reproduction_schedule[0];
+
+    // "decrement" fish in each bucket
+    for i in 0..8 {
+        fish_reproduction_schedule[i] = fish_reproduction_schedule[i + 1];
+    }
+
+    // add in new fish into buckets for 6 & 8 days left
+    fish_reproduction_schedule[6] += num_new_fish;
+    fish_reproduction_schedule[8] = num_new_fish;
+}
+
+// fn advance_day(fish: &mut Vec<u8>) {
+//     let mut num_new_fish = 0;
+//     for i in 0..fish.len() {
+//         if fish[i] == 0 {
+//             num_new_fish += 1;
+//             fish[i] = 6;
+//         } else {
+//             fish[i] -= 1;
+//         }
+//     }
+//
+//     for _ in 0..num_new_fish {
+//         fish.push(8)
+//     }
+// }
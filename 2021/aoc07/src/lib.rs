@@ -0,0 +1,62 @@
+use aoc_core::input::parse_comma_separated;
+use aoc_core::Solution;
+use std::fmt::Display;
+
+type CostFn = fn(pos_a: isize, pos_b: isize) -> usize;
+
+pub struct Day07;
+
+// cost increases monotonically away from optimal target position.
+// can't use binary search with rotation because costs aren't sorted.
+// any way to search the space faster than linear?
+impl Solution for Day07 {
+    type Input = Vec<u16>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_comma_separated(input)
+    }
+
+    fn part1(&self, crab_positions: &Self::Input) -> impl Display {
+        let (_target_pos, fuel_cost) = min_target_pos_and_cost(crab_positions, constant_fuel_cost);
+        fuel_cost
+    }
+
+    fn part2(&self, crab_positions: &Self::Input) -> impl Display {
+        let (_target_pos, fuel_cost) = min_target_pos_and_cost(crab_positions, linear_cost_rate);
+        fuel_cost
+    }
+}
+
+fn min_target_pos_and_cost(crab_positions: &[u16], cost_fn: CostFn) -> (u16, usize) {
+    let max_pos = *crab_positions.iter().max().unwrap();
+    (0..=max_pos)
+        .map(|target_pos| {
+            (
+                target_pos,
+                alignment_cost(crab_positions, target_pos, cost_fn),
+            )
+        })
+        .min_by(|(_, cost_a), (_, cost_b)| cost_a.cmp(cost_b))
+        .unwrap()
+}
+
+fn alignment_cost(crab_positions: &[u16], target_position: u16, cost_fn: CostFn) -> usize {
+    let target_pos_int = isize::try_from(target_position).unwrap();
+    crab_positions
+        .iter()
+        .map(|p| cost_fn(target_pos_int, isize::try_from(*p).unwrap()))
+        .sum()
+}
+
+fn constant_fuel_cost(pos_a: isize, pos_b: isize) -> usize {
+    usize::try_from((pos_a - pos_b).abs()).unwrap()
+}
+
+fn linear_cost_rate(pos_a: isize, pos_b: isize) -> usize {
+    triangle(constant_fuel_cost(pos_a, pos_b))
+}
+
+// https://en.wikipedia.org/wiki/Triangular_number
+fn triangle(n: usize) -> usize {
+    n * (n + 1) / 2
+}
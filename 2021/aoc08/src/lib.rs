@@ -0,0 +1,221 @@
+use aoc_core::parse::{char_tokens, split_once};
+use aoc_core::{ParseError, Solution};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use tracing::trace;
+
+type UniqueDigitCombinations = [String; 10];
+type EncodedNumber = [String; 4];
+type Display = (UniqueDigitCombinations, EncodedNumber);
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Display>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, displays: &Self::Input) -> impl fmt::Display {
+        let unique_segment_counts = [2, 3, 4, 7];
+        displays
+            .iter()
+            .flat_map(|(_, digits)| {
+                digits
+                    .iter()
+                    .filter(|&d| unique_segment_counts.contains(&d.len()))
+            })
+            .count()
+    }
+
+    fn part2(&self, displays: &Self::Input) -> impl fmt::Display {
+        displays.iter().map(decode_display).sum::<usize>()
+    }
+}
+
+fn decode_display(display: &Display) -> usize {
+    let (digit_combos, encoded_digits) = display;
+
+    let digit_decoder_map = deduce_wire_combinations(digit_combos);
+    trace!(?digit_decoder_map);
+
+    let decoded_digits: Vec<usize> = encoded_digits
+        .iter()
+        .map(|combo| BTreeSet::from_iter(combo.chars()))
+        .map(|combo| digit_decoder_map[&combo])
+        .collect();
+
+    trace!(?decoded_digits);
+
+    let mut decoded_display = 0;
+    for i in (0..4).rev() {
+        decoded_display += 10_usize.pow((3 - i) as u32) * decoded_digits[i];
+    }
+    decoded_display
+}
+
+fn deduce_wire_combinations(
+    digit_combos: &UniqueDigitCombinations,
+) -> HashMap<BTreeSet<char>, usize> {
+    let digit_combos: Vec<BTreeSet<char>> = digit_combos
+        .iter()
+        .map(|combo| BTreeSet::from_iter(combo.chars()))
+        .collect();
+
+    trace!(?digit_combos);
+
+    // Can deduce by unique segment count
+    let one: BTreeSet<char> = digit_combos
+        .iter()
+        .find(|combo| combo.len() == 2)
+        .unwrap()
+        .clone();
+    let four: BTreeSet<char> = digit_combos
+        .iter()
+        .find(|combo| combo.len() == 4)
+        .unwrap()
+        .clone();
+    let seven: BTreeSet<char> = digit_combos
+        .iter()
+        .find(|combo| combo.len() == 3)
+        .unwrap()
+        .clone();
+    let eight: BTreeSet<char> = digit_combos
+        .iter()
+        .find(|combo| combo.len() == 7)
+        .unwrap()
+        .clone();
+    trace!(?one, ?four, ?seven, ?eight);
+
+    let top_segment: char = seven.difference(&one).next().copied().unwrap();
+    trace!(?top_segment);
+
+    // Deduce middle segments from intersection of all 5 segment combos
+    let five_segment_combos: Vec<&BTreeSet<char>> = digit_combos
+        .iter()
+        .filter(|combo| combo.len() == 5)
+        .collect();
+    trace!(?five_segment_combos);
+
+    let mut top_middle_bottom_segments: BTreeSet<char> = BTreeSet::new();
+    top_middle_bottom_segments = top_middle_bottom_segments.union(&eight).copied().collect();
+    five_segment_combos.iter().for_each(|combo| {
+        top_middle_bottom_segments = top_middle_bottom_segments
+            .intersection(combo)
+            .copied()
+            .collect();
+    });
+    trace!(?top_middle_bottom_segments);
+
+    let middle_bottom_segments: BTreeSet<char> = top_middle_bottom_segments
+        .difference(&seven)
+        .copied()
+        .collect();
+    trace!(?middle_bottom_segments);
+
+    let middle_segment: char = middle_bottom_segments
+        .intersection(&four)
+        .copied()
+        .next()
+        .unwrap();
+    trace!(?middle_segment);
+
+    let bottom_segment: char = middle_bottom_segments
+        .difference(&BTreeSet::from([middle_segment]))
+        .copied()
+        .next()
+        .unwrap();
+    trace!(?bottom_segment);
+
+    // add middle and bottom segments to 7 to get 3
+    let three: BTreeSet<char> = seven.union(&middle_bottom_segments).copied().collect();
+    trace!(?three);
+
+    let top_left_segment: char = four
+        .difference(
+            &one.union(&BTreeSet::from([middle_segment]))
+                .copied()
+                .collect(),
+        )
+        .copied()
+        .next()
+        .unwrap();
+    trace!(?top_left_segment);
+
+    let two_and_five: Vec<&BTreeSet<char>> = five_segment_combos
+        .into_iter()
+        .filter(|&combo| combo != &three)
+        .collect();
+    trace!(?two_and_five);
+
+    let five: BTreeSet<char> = two_and_five
+        .iter()
+        .find(|&&combo| combo.contains(&top_left_segment))
+        .copied()
+        .unwrap()
+        .clone();
+    trace!(?five);
+
+    let two: BTreeSet<char> = two_and_five
+        .into_iter()
+        .find(|&combo| combo != &five)
+        .unwrap()
+        .clone();
+    trace!(?two);
+
+    let zero: BTreeSet<char> = eight
+        .difference(&BTreeSet::from([middle_segment]))
+        .copied()
+        .collect();
+    trace!(?zero);
+
+    let nine: BTreeSet<char> = four
+        .union(&BTreeSet::from([top_segment, bottom_segment]))
+        .copied()
+        .collect();
+    trace!(?nine);
+
+    let bottom_left_segment: char = eight.difference(&nine).copied().next().unwrap();
+    trace!(?bottom_left_segment);
+
+    let six: BTreeSet<char> = five
+        .union(&BTreeSet::from([bottom_left_segment]))
+        .copied()
+        .collect();
+    trace!(?six);
+
+    HashMap::from_iter(
+        vec![zero, one, two, three, four, five, six, seven, eight, nine]
+            .into_iter()
+            .zip(0..=9),
+    )
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<Display>> {
+    input
+        .lines()
+        .map(|line| {
+            let (digit_combinations, four_digit_display) = split_once(line, " | ")?;
+            Ok((
+                into_array(digit_combinations)?,
+                into_array(four_digit_display)?,
+            ))
+        })
+        .collect()
+}
+
+fn into_array<const N: usize>(s: &str) -> aoc_core::Result<[String; N]> {
+    let patterns = s
+        .split_whitespace()
+        .map(
+            |pattern| match char_tokens(pattern).find(|c| !("a"..="g").contains(c)) {
+                Some(c) => Err(ParseError::new(c, "expected a segment from a to g")),
+                None => Ok(pattern.to_string()),
+            },
+        )
+        .collect::<aoc_core::Result<Vec<String>>>()?;
+    patterns
+        .try_into()
+        .map_err(|_| ParseError::new(s, format!("expected {} patterns in", N)))
+}
@@ -0,0 +1,128 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::image::{self, ExportImage, Rgb};
+use aoc_core::search;
+use aoc_core::{ParseError, Part, Solution};
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct Heightmap {
+    grid: Grid<u8>,
+}
+
+impl Heightmap {
+    fn new(grid: Grid<u8>) -> Heightmap {
+        Heightmap { grid }
+    }
+
+    fn find_basins(&self, low_points: &[Pos]) -> Vec<HashSet<Pos>> {
+        // for each low point, explore around until encountering a 9 or edge
+        low_points
+            .iter()
+            .map(|pos| {
+                let neighbors =
+                    |&p: &Pos| self.grid.neighbors4(p).filter(|&n| self.get_height(n) != 9);
+                let basin = search::bfs(*pos, neighbors, |_| false);
+                basin.into_distances().into_keys().collect()
+            })
+            .collect()
+    }
+
+    fn find_low_points(&self) -> Vec<Pos> {
+        self.grid
+            .positions()
+            .filter(|pos| self.is_low_point(*pos))
+            .collect()
+    }
+
+    fn is_low_point(&self, pos: Pos) -> bool {
+        let curr_height = self.get_height(pos);
+        self.grid
+            .neighbors4(pos)
+            .all(|p| curr_height < self.get_height(p))
+    }
+
+    fn get_height(&self, pos: Pos) -> u8 {
+        self.grid[pos]
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Heightmap;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        let heightmap = Heightmap::new(parse_input(input)?);
+        // part 2 multiplies the sizes of the 3 largest basins, and each low point has one
+        if heightmap.find_low_points().len() < 3 {
+            return Err(ParseError::missing(
+                input,
+                "a heightmap with at least 3 basins",
+            ));
+        }
+        Ok(heightmap)
+    }
+
+    fn part1(&self, heightmap: &Self::Input) -> impl Display {
+        let low_points = heightmap.find_low_points();
+
+        let risk_levels: Vec<usize> = low_points
+            .iter()
+            .map(|c| usize::from(heightmap.get_height(*c) + 1))
+            .collect();
+
+        risk_levels.iter().sum::<usize>()
+    }
+
+    fn part2(&self, heightmap: &Self::Input) -> impl Display {
+        let low_points = heightmap.find_low_points();
+        let basins: Vec<HashSet<Pos>> = heightmap.find_basins(&low_points);
+        let mut basin_sizes: BinaryHeap<_> = basins.iter().map(|b| b.len()).collect();
+
+        let num_largest = 3;
+        let largest_basin_sizes: Vec<_> = (0..num_largest)
+            .map(|_| basin_sizes.pop().unwrap())
+            .collect();
+
+        largest_basin_sizes.into_iter().product::<usize>()
+    }
+}
+
+// Part 1 shades the heights from black up to white with the low points in red, part 2 gives
+// each basin its own color and leaves the height 9 ridges between them black
+impl ExportImage for Day09 {
+    fn export_image(&self, part: Part, heightmap: &Self::Input, path: &Path) -> io::Result<()> {
+        let low_points = heightmap.find_low_points();
+        let colors = match part {
+            Part::One => {
+                let mut colors = heightmap.grid.map(|&height| Rgb::gray(height * 28));
+                for pos in low_points {
+                    colors[pos] = Rgb(255, 0, 0);
+                }
+                colors
+            }
+            Part::Two => {
+                let mut colors = heightmap.grid.map(|_| Rgb::BLACK);
+                for (i, basin) in heightmap.find_basins(&low_points).iter().enumerate() {
+                    for &pos in basin {
+                        colors[pos] = Rgb::distinct(i);
+                    }
+                }
+                colors
+            }
+        };
+        image::save(path, &colors, |&color| color)
+    }
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Grid<u8>> {
+    Grid::parse(input, parse_height)
+}
+
+fn parse_height(c: &str) -> aoc_core::Result<u8> {
+    c.parse()
+        .map_err(|_| ParseError::new(c, "expected a height from 0 to 9"))
+}
@@ -0,0 +1,127 @@
+use aoc_core::{ParseError, Solution};
+use phf::phf_map;
+use std::fmt::Display;
+
+static CHUNK_SYMBOL_COMPLEMENTS: phf::Map<char, char> = phf_map! {
+    '(' => ')',
+    '[' => ']',
+    '{' => '}',
+    '<' => '>',
+};
+
+static SYNTAX_ERROR_SCORES: phf::Map<char, usize> = phf_map! {
+    ')' => 3,
+    ']' => 57,
+    '}' => 1197,
+    '>' => 25137,
+};
+
+static AUTOCOMPLETE_SCORES: phf::Map<char, usize> = phf_map! {
+    ')' => 1,
+    ']' => 2,
+    '}' => 3,
+    '>' => 4,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChunkParseResult {
+    Complete,
+    Incomplete(String),
+    Corrupted(char),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<ChunkParseResult>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        let parsed_chunks: Vec<_> = input
+            .lines()
+            .map(parse_chunks)
+            .collect::<aoc_core::Result<_>>()?;
+        // part 2 takes the middle score of the incomplete lines
+        if !parsed_chunks
+            .iter()
+            .any(|e| matches!(e, ChunkParseResult::Incomplete(_)))
+        {
+            return Err(ParseError::missing(input, "an incomplete line"));
+        }
+        Ok(parsed_chunks)
+    }
+
+    fn part1(&self, parsed_chunks: &Self::Input) -> impl Display {
+        parsed_chunks
+            .iter()
+            .map(|e| match e {
+                ChunkParseResult::Corrupted(c) => SYNTAX_ERROR_SCORES[c],
+                _ => 0,
+            })
+            .sum::<usize>()
+    }
+
+    fn part2(&self, parsed_chunks: &Self::Input) -> impl Display {
+        let mut autocomplete_scores: Vec<usize> = parsed_chunks
+            .iter()
+            .map(|e| match e {
+                ChunkParseResult::Incomplete(s) => score_autocomplete_string(s),
+                _ => 0,
+            })
+            .filter(|score| score != &0)
+            .collect();
+
+        autocomplete_scores.sort();
+        autocomplete_scores[autocomplete_scores.len() / 2]
+    }
+}
+
+fn parse_chunks(syntax_line: &str) -> aoc_core::Result<ChunkParseResult> {
+    let mut open_chunk_stack: Vec<char> = vec![];
+    for (i, c) in syntax_line.char_indices() {
+        if !CHUNK_SYMBOL_COMPLEMENTS.contains_key(&c) && !SYNTAX_ERROR_SCORES.contains_key(&c) {
+            let token = &syntax_line[i..i + c.len_utf8()];
+            return Err(ParseError::new(token, "unknown chunk symbol"));
+        }
+
+        if CHUNK_SYMBOL_COMPLEMENTS.contains_key(&c) {
+            // opening chunk symbol
+            // Add to stack and move to next char
+            open_chunk_stack.push(c);
+        } else {
+            // closing chunk symbol
+            // Peek stack to verify symbols are complements
+            // If so, pop opening chunk symbol off stack, otherwise return Corrupted result
+            match open_chunk_stack.last() {
+                // Not sure about this one. This is like if we have an extra closing tag with no associated opening tag
+                None => {
+                    return Ok(ChunkParseResult::Corrupted(c));
+                }
+                Some(&opening) if CHUNK_SYMBOL_COMPLEMENTS[&opening] == c => {
+                    open_chunk_stack.pop();
+                }
+                Some(_) => {
+                    return Ok(ChunkParseResult::Corrupted(c));
+                }
+            }
+        }
+    }
+
+    if open_chunk_stack.is_empty() {
+        Ok(ChunkParseResult::Complete)
+    } else {
+        let autocomplete = open_chunk_stack
+            .into_iter()
+            .rev()
+            .map(|opening| CHUNK_SYMBOL_COMPLEMENTS[&opening])
+            .collect::<String>();
+        Ok(ChunkParseResult::Incomplete(autocomplete))
+    }
+}
+
+fn score_autocomplete_string(autocomplete_string: &str) -> usize {
+    let mut total_score = 0;
+    for c in autocomplete_string.chars() {
+        total_score = total_score * 5 + AUTOCOMPLETE_SCORES[&c]
+    }
+    total_score
+}
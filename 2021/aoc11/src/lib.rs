@@ -0,0 +1,136 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::viz::{self, Cell, Color, Frame, Visualize};
+use aoc_core::{ParseError, Part, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+type DumboOctopusEnergyLevels = Grid<u8>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = DumboOctopusEnergyLevels;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, initial_energy_levels: &Self::Input) -> impl Display {
+        let mut energy_levels = initial_energy_levels.clone();
+        let num_steps = 100;
+        let mut total_flashes: usize = 0;
+        for _ in 0..num_steps {
+            total_flashes += step(&mut energy_levels);
+        }
+
+        total_flashes
+    }
+
+    fn part2(&self, initial_energy_levels: &Self::Input) -> impl Display {
+        // first step where all octopuses flash
+        let mut energy_levels = initial_energy_levels.clone();
+        let mut flashes = 0;
+        let mut steps = 0;
+        while flashes != energy_levels.len() {
+            flashes = step(&mut energy_levels);
+            steps += 1;
+        }
+
+        steps
+    }
+}
+
+// The octopuses after a step, with the ones that just flashed lit up
+#[derive(Clone)]
+struct OctopusFrame {
+    step: usize,
+    energy_levels: DumboOctopusEnergyLevels,
+    flashes: usize,
+}
+
+impl Frame for OctopusFrame {
+    fn draw(&self) -> Grid<Cell> {
+        self.energy_levels.map(|&energy_level| {
+            let digit = char::from(b'0' + energy_level);
+            match energy_level {
+                0 => Cell::new(digit, Color::Yellow),
+                _ => Cell::new(digit, Color::Gray),
+            }
+        })
+    }
+
+    fn caption(&self) -> String {
+        format!("step {}: {} flashed", self.step, self.flashes)
+    }
+}
+
+impl Visualize for Day11 {
+    fn frames(
+        &self,
+        part: Part,
+        initial_energy_levels: &Self::Input,
+    ) -> impl Iterator<Item = impl Frame> {
+        let initial = OctopusFrame {
+            step: 0,
+            energy_levels: initial_energy_levels.clone(),
+            flashes: 0,
+        };
+        viz::steps(initial, move |frame| {
+            frame.flashes = step(&mut frame.energy_levels);
+            frame.step += 1;
+            match part {
+                Part::One => frame.step < 100,
+                Part::Two => frame.flashes != frame.energy_levels.len(),
+            }
+        })
+    }
+}
+
+fn step(energy_levels: &mut DumboOctopusEnergyLevels) -> usize {
+    // First, the energy level of each octopus increases by 1.
+    energy_levels
+        .iter_mut()
+        .for_each(|(_, energy_level)| *energy_level += 1);
+
+    // Then, any octopus with an energy level greater than 9 flashes.
+    // This increases the energy level of all adjacent octopuses by 1, including octopuses that are diagonally adjacent.
+    // If this causes an octopus to have an energy level greater than 9, it also flashes.
+    // This process continues as long as new octopuses keep having their energy level increased beyond 9. (An octopus can only flash at most once per step.)
+    let mut flashed: HashSet<Pos> = HashSet::new();
+
+    for pos in energy_levels.positions() {
+        if flashed.contains(&pos) {
+            continue;
+        }
+        if energy_levels[pos] > 9 {
+            flash(energy_levels, &mut flashed, pos);
+        }
+    }
+
+    // Finally, any octopus that flashed during this step has its energy level set to 0, as it used all of its energy to flash.
+    for pos in &flashed {
+        energy_levels[*pos] = 0;
+    }
+
+    flashed.len()
+}
+
+// Mark current octopus as flashed
+// Increase energy levels of surrounding octopuses
+// Trigger flash on any neighbors if energy level is greater than 9 and they haven't already flashed
+fn flash(energy_levels: &mut DumboOctopusEnergyLevels, flashed: &mut HashSet<Pos>, octo_pos: Pos) {
+    flashed.insert(octo_pos);
+    for neighbor_pos in energy_levels.neighbors8(octo_pos) {
+        energy_levels[neighbor_pos] += 1;
+        if energy_levels[neighbor_pos] > 9 && !flashed.contains(&neighbor_pos) {
+            flash(energy_levels, flashed, neighbor_pos);
+        }
+    }
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<DumboOctopusEnergyLevels> {
+    Grid::parse(input, |c| {
+        c.parse()
+            .map_err(|_| ParseError::new(c, "expected an energy level from 0 to 9"))
+    })
+}
@@ -0,0 +1,117 @@
+use aoc_core::parse::split_once;
+use aoc_core::Solution;
+use std::collections::HashMap;
+use std::fmt::Display;
+use tracing::trace;
+
+#[derive(Debug)]
+pub struct CaveNetwork {
+    adj_list: HashMap<String, Vec<String>>,
+}
+
+impl CaveNetwork {
+    fn new(edges: Vec<(String, String)>) -> CaveNetwork {
+        let mut adj_list: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (a, b) in edges {
+            let traversable_from_a = adj_list.entry(a.clone()).or_default();
+            traversable_from_a.push(b.clone());
+            let traversable_from_b = adj_list.entry(b).or_default();
+            traversable_from_b.push(a);
+        }
+
+        CaveNetwork { adj_list }
+    }
+
+    // Part 1 only allows visiting small caves once, part 2 lets a single small cave be visited twice
+    fn explore_all_paths(&self, allow_small_cave_twice: bool) -> Vec<Vec<String>> {
+        let mut paths_to_end = vec![];
+        self.explore_all_paths_helper(
+            allow_small_cave_twice,
+            "start".to_string(),
+            vec![],
+            HashMap::new(),
+            &mut paths_to_end,
+        );
+        paths_to_end
+    }
+
+    fn explore_all_paths_helper(
+        &self,
+        allow_small_cave_twice: bool,
+        curr_cave: String,
+        mut curr_path: Vec<String>,
+        mut visited_small_caves: HashMap<String, u8>,
+        paths_to_end: &mut Vec<Vec<String>>,
+    ) {
+        trace!(?curr_path, ?curr_cave);
+        curr_path.push(curr_cave.clone());
+        if curr_cave == "end" {
+            paths_to_end.push(curr_path);
+        } else {
+            // not the end KEEP SEARCHING
+
+            let visited = visited_small_caves.contains_key(&curr_cave);
+            let visited_once = visited_small_caves
+                .get(&curr_cave)
+                .map_or_else(|| false, |n| n == &1);
+            let small_cave_visited_twice = visited_small_caves.values().any(|n| n == &2);
+            let can_revisit = allow_small_cave_twice
+                && visited_once
+                && !small_cave_visited_twice
+                && curr_cave != "start";
+
+            if !visited || can_revisit {
+                if !curr_cave.chars().next().unwrap().is_uppercase() {
+                    // small caves can only be searched once
+                    let cave_visits = visited_small_caves.entry(curr_cave.clone()).or_insert(0);
+                    *cave_visits += 1;
+                }
+
+                // copy current path and branch out from here
+                let maybe_neighbor_caves = self.adj_list.get(&curr_cave);
+                if let Some(neighbor_caves) = maybe_neighbor_caves {
+                    for neighbor in neighbor_caves {
+                        self.explore_all_paths_helper(
+                            allow_small_cave_twice,
+                            neighbor.clone(),
+                            curr_path.clone(),
+                            visited_small_caves.clone(),
+                            paths_to_end,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = CaveNetwork;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, cave: &Self::Input) -> impl Display {
+        cave.explore_all_paths(false).len()
+    }
+
+    fn part2(&self, cave: &Self::Input) -> impl Display {
+        cave.explore_all_paths(true).len()
+    }
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<CaveNetwork> {
+    let edges: Vec<(String, String)> = input
+        .lines()
+        .map(|line| {
+            let (a, b) = split_once(line, "-")?;
+            Ok((a.to_string(), b.to_string()))
+        })
+        .collect::<aoc_core::Result<_>>()?;
+
+    Ok(CaveNetwork::new(edges))
+}
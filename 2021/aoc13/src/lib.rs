@@ -0,0 +1,263 @@
+use aoc_core::grid::Grid;
+use aoc_core::parse::{parse_token, split_once, strip_prefix};
+use aoc_core::viz::{self, Cell, Color, Frame, Visualize};
+use aoc_core::{ParseError, Part, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+type Coordinate = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+type FoldInstruction = (Axis, usize);
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<Coordinate>, Vec<FoldInstruction>);
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (coords, fold_instructions): &Self::Input) -> impl Display {
+        let mut coord_set: HashSet<Coordinate> = coords.iter().copied().collect();
+
+        apply_fold(fold_instructions[0], &mut coord_set);
+        coord_set.len()
+    }
+
+    fn part2(&self, (coords, fold_instructions): &Self::Input) -> impl Display {
+        let mut coord_set: HashSet<Coordinate> = coords.iter().copied().collect();
+
+        for f in fold_instructions {
+            apply_fold(*f, &mut coord_set);
+        }
+        render_coords(&coord_set)
+    }
+}
+
+// The paper after some folds, with the line it's folded along next
+#[derive(Clone)]
+struct PaperFrame {
+    folds: usize,
+    coord_set: HashSet<Coordinate>,
+    next_fold: Option<FoldInstruction>,
+}
+
+impl Frame for PaperFrame {
+    fn draw(&self) -> Grid<Cell> {
+        let fold_line = |axis| match self.next_fold {
+            Some((fold_axis, value)) if fold_axis == axis => Some(value),
+            _ => None,
+        };
+        let (fold_x, fold_y) = (fold_line(Axis::Vertical), fold_line(Axis::Horizontal));
+
+        let max_x = self
+            .coord_set
+            .iter()
+            .map(|(x, _)| *x)
+            .chain(fold_x)
+            .max()
+            .unwrap_or(0);
+        let max_y = self
+            .coord_set
+            .iter()
+            .map(|(_, y)| *y)
+            .chain(fold_y)
+            .max()
+            .unwrap_or(0);
+        Grid::from_fn(max_y + 1, max_x + 1, |pos| {
+            let (x, y) = (pos.col, pos.row);
+            if self.coord_set.contains(&(x, y)) {
+                Cell::new('#', Color::White)
+            } else if fold_x == Some(x) {
+                Cell::new('|', Color::Red)
+            } else if fold_y == Some(y) {
+                Cell::new('-', Color::Red)
+            } else {
+                Cell::new('.', Color::Gray)
+            }
+        })
+    }
+
+    fn caption(&self) -> String {
+        format!("{} folds: {} dots", self.folds, self.coord_set.len())
+    }
+}
+
+impl Visualize for Day13 {
+    fn frames(
+        &self,
+        part: Part,
+        (coords, fold_instructions): &Self::Input,
+    ) -> impl Iterator<Item = impl Frame> {
+        let num_folds = match part {
+            Part::One => 1,
+            Part::Two => fold_instructions.len(),
+        };
+        let initial = PaperFrame {
+            folds: 0,
+            coord_set: coords.iter().copied().collect(),
+            next_fold: fold_instructions.first().copied(),
+        };
+        viz::steps(initial, move |frame| {
+            let Some(&fold) = fold_instructions.get(frame.folds) else {
+                return false;
+            };
+            apply_fold(fold, &mut frame.coord_set);
+            frame.folds += 1;
+            frame.next_fold = fold_instructions
+                .get(frame.folds)
+                .copied()
+                .filter(|_| frame.folds < num_folds);
+            frame.folds < num_folds
+        })
+        // without any folds, the paper is only shown as it starts
+        .take(num_folds + 1)
+    }
+}
+
+fn apply_fold(f: FoldInstruction, coord_set: &mut HashSet<Coordinate>) {
+    let (fold_axis, fold_value) = f;
+    let affected_coords: Vec<_> = coord_set
+        .iter()
+        .filter(|(x, y)| {
+            let coord_value = match fold_axis {
+                Axis::Vertical => x,
+                Axis::Horizontal => y,
+            };
+            coord_value > &fold_value
+        })
+        .cloned()
+        .collect();
+
+    for c in affected_coords {
+        flip_across_fold(coord_set, c, f);
+    }
+}
+
+fn flip_across_fold(
+    coord_set: &mut HashSet<Coordinate>,
+    coord: Coordinate,
+    fold_instruction: FoldInstruction,
+) {
+    let (x, y) = coord;
+    let (fold_axis, fold_value) = fold_instruction;
+
+    let distance_from_fold_line = match fold_axis {
+        Axis::Horizontal => y - fold_value,
+        Axis::Vertical => x - fold_value,
+    };
+
+    let across_fold_line = fold_value - distance_from_fold_line;
+
+    let folded_coord = match fold_axis {
+        Axis::Horizontal => (x, across_fold_line),
+        Axis::Vertical => (across_fold_line, y),
+    };
+
+    coord_set.remove(&coord);
+    coord_set.insert(folded_coord);
+}
+
+// The code is spelled out in the dots, so the answer is the rendered paper
+fn render_coords(coord_set: &HashSet<Coordinate>) -> String {
+    let max_x = coord_set.iter().map(|(x, _)| x).max().unwrap();
+    let max_y = coord_set.iter().map(|(_, y)| y).max().unwrap();
+
+    (0..=*max_y)
+        .map(|y| {
+            (0..=*max_x)
+                .map(|x| {
+                    if coord_set.contains(&(x, y)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<(Vec<Coordinate>, Vec<FoldInstruction>)> {
+    let (coord_str, instr_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::missing(input, "fold instructions after a blank line"))?;
+
+    let coords: Vec<Coordinate> = coord_str
+        .lines()
+        .map(|l| {
+            let (x, y) = split_once(l, ",")?;
+            Ok((parse_token(x)?, parse_token(y)?))
+        })
+        .collect::<aoc_core::Result<_>>()?;
+
+    let instr_prefix = "fold along ";
+    let fold_instructions: Vec<FoldInstruction> = instr_str
+        .lines()
+        .map(|l| {
+            let (axis, value) = split_once(strip_prefix(l, instr_prefix)?, "=")?;
+            let axis = match axis {
+                "x" => Axis::Vertical,
+                "y" => Axis::Horizontal,
+                other => return Err(ParseError::new(other, "invalid axis type")),
+            };
+            Ok((axis, parse_token(value)?))
+        })
+        .collect::<aoc_core::Result<_>>()?;
+
+    Ok((coords, fold_instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // A fold and dots on either side of it, never past twice the fold line since the paper
+    // folds over onto itself. Dots are never on the fold line in the puzzle, but folding should
+    // leave those where they are
+    fn dots_and_fold() -> impl Strategy<Value = (Vec<Coordinate>, FoldInstruction)> {
+        (
+            prop_oneof![Just(Axis::Vertical), Just(Axis::Horizontal)],
+            1usize..20,
+        )
+            .prop_flat_map(|(axis, fold_value)| {
+                let dot =
+                    (0..=2 * fold_value, 0usize..40).prop_map(move |(along, across)| match axis {
+                        Axis::Vertical => (along, across),
+                        Axis::Horizontal => (across, along),
+                    });
+                (prop::collection::vec(dot, 0..50), Just((axis, fold_value)))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn fold_keeps_at_least_half_the_dots((dots, fold) in dots_and_fold()) {
+            let mut coord_set: HashSet<Coordinate> = dots.into_iter().collect();
+            let before = coord_set.len();
+            apply_fold(fold, &mut coord_set);
+
+            // each dot lands on itself or the one it's folded onto, so at most two merge
+            prop_assert!(coord_set.len() <= before);
+            prop_assert!(coord_set.len() * 2 >= before);
+
+            let (axis, fold_value) = fold;
+            for (x, y) in coord_set {
+                let coord_value = match axis {
+                    Axis::Vertical => x,
+                    Axis::Horizontal => y,
+                };
+                prop_assert!(coord_value <= fold_value);
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_core::parse::split_once;
+use aoc_core::{ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (String, HashMap<String, String>);
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (polymer, pair_insertion_rules): &Self::Input) -> impl Display {
+        polymerize(polymer, pair_insertion_rules, 10)
+    }
+
+    fn part2(&self, (polymer, pair_insertion_rules): &Self::Input) -> impl Display {
+        polymerize(polymer, pair_insertion_rules, 40)
+    }
+}
+
+// Returns the quantity of the most common element minus the quantity of the least common element
+fn polymerize(
+    polymer: &str,
+    pair_insertion_rules: &HashMap<String, String>,
+    num_steps: usize,
+) -> usize {
+    let mut element_pairs = to_element_pairs(polymer);
+
+    let mut pair_transformations: HashMap<String, Vec<String>> = HashMap::new();
+    for (pair, inserted_char) in pair_insertion_rules.iter() {
+        pair_transformations.insert(
+            pair.clone(),
+            vec![
+                String::from_iter([pair.get(0..1).unwrap(), inserted_char]),
+                String::from_iter([inserted_char, pair.get(1..).unwrap()]),
+            ],
+        );
+    }
+
+    for _ in 0..num_steps {
+        element_pairs = polymerize_pairs(&element_pairs, &pair_transformations);
+    }
+
+    let element_counts = char_counts(&element_pairs, polymer);
+    let most_common = element_counts.values().max().unwrap();
+    let least_common = element_counts.values().min().unwrap();
+    most_common - least_common
+}
+
+fn polymerize_pairs(
+    element_pairs: &HashMap<String, usize>,
+    pair_transformations: &HashMap<String, Vec<String>>,
+) -> HashMap<String, usize> {
+    let mut next_element_pairs: HashMap<String, usize> = HashMap::new();
+    for (pair, num_pairs) in element_pairs {
+        for new_pair in pair_transformations.get(pair).unwrap() {
+            next_element_pairs
+                .entry(String::from(new_pair))
+                .and_modify(|n| *n += num_pairs)
+                .or_insert(*num_pairs);
+        }
+    }
+
+    next_element_pairs.retain(|_, num_pairs| num_pairs != &0);
+    next_element_pairs
+}
+
+fn to_element_pairs(polymer_template: &str) -> HashMap<String, usize> {
+    let mut element_pairs = HashMap::new();
+    for i in 0..(polymer_template.len() - 1) {
+        let pair = &polymer_template[i..=(i + 1)];
+        let num_pairs = element_pairs.entry(pair.to_string()).or_insert(0);
+        *num_pairs += 1;
+    }
+    element_pairs
+}
+
+fn char_counts(
+    pairs: &HashMap<String, usize>,
+    original_polymer_template: &str,
+) -> HashMap<char, usize> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for (pair, num_pairs) in pairs {
+        for c in pair.chars() {
+            *counts.entry(c).or_insert(0) += num_pairs;
+        }
+    }
+
+    let all_chars: Vec<char> = pairs.keys().flat_map(|p| p.chars()).unique().collect();
+    let first_char = original_polymer_template.chars().next().unwrap();
+    let last_char = original_polymer_template
+        .chars()
+        .nth(original_polymer_template.len() - 1)
+        .unwrap();
+
+    // We double count all chars _except_ for the first and last char of the original polymer
+    for c in all_chars {
+        let num_cs = counts.entry(c).or_insert(0);
+        *num_cs = if c == first_char || c == last_char {
+            *num_cs / 2 + 1
+        } else {
+            *num_cs / 2
+        };
+    }
+
+    counts
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<(String, HashMap<String, String>)> {
+    let (polymer_template, pair_insertion_rules_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::missing(input, "insertion rules after a blank line"))?;
+
+    let pair_insertion_rules = pair_insertion_rules_str
+        .lines()
+        .map(|l| {
+            let (pair, insertion) = split_once(l, " -> ")?;
+            Ok((pair.to_string(), insertion.to_string()))
+        })
+        .collect::<aoc_core::Result<_>>()?;
+    Ok((polymer_template.to_string(), pair_insertion_rules))
+}
@@ -0,0 +1,84 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::image::{self, ExportImage, Rgb};
+use aoc_core::search::{self, Search};
+use aoc_core::{ParseError, Part, Solution};
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, risk_levels: &Self::Input) -> impl Display {
+        let cost_to_exit = shortest_path_cost(risk_levels, Pos::new(0, 0), exit(risk_levels));
+        cost_to_exit.expect("exit not reachable")
+    }
+
+    fn part2(&self, risk_levels: &Self::Input) -> impl Display {
+        let extended_cavern = extend_cavern(risk_levels, 5);
+        let cost_to_exit =
+            shortest_path_cost(&extended_cavern, Pos::new(0, 0), exit(&extended_cavern));
+        cost_to_exit.expect("exit not reachable")
+    }
+}
+
+// Darker is less risky, with the safest path to the exit in red
+impl ExportImage for Day15 {
+    fn export_image(&self, part: Part, risk_levels: &Self::Input, path: &Path) -> io::Result<()> {
+        let cavern = match part {
+            Part::One => risk_levels.clone(),
+            Part::Two => extend_cavern(risk_levels, 5),
+        };
+        let safest_path = find_exit(&cavern, Pos::new(0, 0), exit(&cavern))
+            .path()
+            .ok_or_else(|| io::Error::other("exit not reachable"))?;
+
+        let mut colors = cavern.map(|&risk| Rgb::gray(risk * 28));
+        for pos in safest_path {
+            colors[pos] = Rgb(255, 0, 0);
+        }
+        image::save(path, &colors, |&color| color)
+    }
+}
+
+fn exit(risk_levels: &Grid<u8>) -> Pos {
+    Pos::new(risk_levels.rows() - 1, risk_levels.cols() - 1)
+}
+
+fn shortest_path_cost(risk_levels: &Grid<u8>, start_pos: Pos, dest_pos: Pos) -> Option<usize> {
+    find_exit(risk_levels, start_pos, dest_pos).cost()
+}
+
+fn find_exit(risk_levels: &Grid<u8>, start_pos: Pos, dest_pos: Pos) -> Search<Pos> {
+    let neighbors = |&pos: &Pos| {
+        risk_levels
+            .neighbors4(pos)
+            .map(|neighbor| (neighbor, usize::from(risk_levels[neighbor])))
+    };
+    search::dijkstra(start_pos, neighbors, |&pos| pos == dest_pos)
+}
+
+// The full cavern is the scanned one tiled `times` times in each direction, with risk levels
+// going up by one for each tile away from the top left, wrapping back around to 1 after 9
+fn extend_cavern(risk_levels: &Grid<u8>, times: usize) -> Grid<u8> {
+    let (rows, cols) = (risk_levels.rows(), risk_levels.cols());
+    Grid::from_fn(rows * times, cols * times, |pos| {
+        let (row_tile, row) = (pos.row / rows, pos.row % rows);
+        let (col_tile, col) = (pos.col / cols, pos.col % cols);
+        let risk = usize::from(risk_levels[Pos::new(row, col)]) + row_tile + col_tile;
+        ((risk - 1) % 9 + 1) as u8
+    })
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Grid<u8>> {
+    Grid::parse(input, |c| {
+        c.parse()
+            .map_err(|_| ParseError::new(c, "expected a risk level from 0 to 9"))
+    })
+}
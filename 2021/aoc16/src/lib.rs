@@ -0,0 +1,433 @@
+use crate::LengthType::*;
+use crate::PacketPayload::*;
+use aoc_core::parse::char_tokens;
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum LengthType {
+    TotalBitLength(usize),
+    NumSubPackets(usize),
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum PacketPayload {
+    Literal(usize),
+    Operator {
+        length_type: LengthType,
+        sub_packets: Vec<Packet>,
+    },
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Packet {
+    version: u8,
+    type_id: u8,
+    payload: PacketPayload,
+}
+
+impl Packet {
+    // Returns None if the packet runs past the end of the bits
+    fn parse(binary_str: &str) -> Option<(Packet, usize)> {
+        let version = <u8>::from_str_radix(binary_str.get(0..3)?, 2).ok()?;
+        let type_id = <u8>::from_str_radix(binary_str.get(3..6)?, 2).ok()?;
+
+        let payload_fn = if type_id == 4 {
+            Packet::parse_literal_payload
+        } else {
+            Packet::parse_operator_payload
+        };
+        let (payload, num_bits_read) = payload_fn(&binary_str[6..])?;
+
+        Some((
+            Packet {
+                version,
+                type_id,
+                payload,
+            },
+            6 + num_bits_read,
+        ))
+    }
+
+    fn parse_literal_payload(literal_payload_str: &str) -> Option<(PacketPayload, usize)> {
+        // look at 5 char chunks, first char is sentinel, extract last 4 digits
+        let mut payload_bits: Vec<u8> = vec![];
+        let mut num_bits_read = 0;
+        loop {
+            let payload_chunk = literal_payload_str
+                .as_bytes()
+                .get(num_bits_read..num_bits_read + 5)?;
+            num_bits_read += 5;
+            for payload_bit in &payload_chunk[1..] {
+                payload_bits.push(*payload_bit);
+            }
+
+            if payload_chunk[0] == b'0' {
+                break;
+            }
+        }
+
+        let payload_value_str: String = payload_bits.into_iter().map(|b| b as char).collect();
+        let payload_value = usize::from_str_radix(&payload_value_str, 2).ok()?;
+
+        Some((Literal(payload_value), num_bits_read))
+    }
+
+    fn parse_operator_payload(operator_payload_str: &str) -> Option<(PacketPayload, usize)> {
+        let first_bit = operator_payload_str.chars().next()?;
+        let (length_type_fn, sub_packet_offset): (fn(usize) -> LengthType, usize) =
+            if first_bit == '0' {
+                (TotalBitLength, 16)
+            } else {
+                (NumSubPackets, 12)
+            };
+
+        let length_val =
+            usize::from_str_radix(operator_payload_str.get(1..sub_packet_offset)?, 2).ok()?;
+        let length_type = length_type_fn(length_val);
+
+        let mut num_packet_bits_read = 0;
+        let mut sub_packets: Vec<Packet> = vec![];
+
+        while match &length_type {
+            TotalBitLength(sub_packet_bits_len) => {
+                // continue parsing packets until we've parsed sub_packets_len or more
+                num_packet_bits_read < *sub_packet_bits_len
+            }
+            NumSubPackets(num_sub_packets) => {
+                // continue parsing until num_sub_packets have been parsed_packet
+                sub_packets.len() < *num_sub_packets
+            }
+        } {
+            let (sub_packet, bits_read) = Packet::parse(
+                operator_payload_str.get((num_packet_bits_read + sub_packet_offset)..)?,
+            )?;
+            sub_packets.push(sub_packet);
+            num_packet_bits_read += bits_read;
+        }
+
+        Some((
+            Operator {
+                length_type,
+                sub_packets,
+            },
+            sub_packet_offset + num_packet_bits_read,
+        ))
+    }
+
+    fn versions(&self) -> Vec<u8> {
+        match &self.payload {
+            Literal(_) => vec![self.version],
+            Operator {
+                length_type: _,
+                sub_packets,
+            } => {
+                let mut versions = vec![self.version];
+                for sub_packet in sub_packets {
+                    versions.append(&mut sub_packet.versions());
+                }
+                versions
+            }
+        }
+    }
+
+    fn eval(&self) -> usize {
+        match &self.payload {
+            Literal(val) => *val,
+            PacketPayload::Operator {
+                length_type: _,
+                sub_packets,
+            } => {
+                let sub_packet_vals: Vec<usize> = sub_packets.iter().map(|p| p.eval()).collect();
+                match self.type_id {
+                    0 => sub_packet_vals.into_iter().sum(),
+                    1 => sub_packet_vals.into_iter().product(),
+                    2 => sub_packet_vals.into_iter().min().unwrap(),
+                    3 => sub_packet_vals.into_iter().max().unwrap(),
+                    5 => (sub_packet_vals[0] > sub_packet_vals[1]) as usize,
+                    6 => (sub_packet_vals[0] < sub_packet_vals[1]) as usize,
+                    7 => (sub_packet_vals[0] == sub_packet_vals[1]) as usize,
+                    tid => panic!("detected invalid operator type id: {}", tid),
+                }
+            }
+        }
+    }
+}
+
+fn to_binary_str(hex_str: &str) -> aoc_core::Result<String> {
+    char_tokens(hex_str)
+        .map(|hex_digit| match u8::from_str_radix(hex_digit, 16) {
+            Ok(n) => Ok(format!("{:04b}", n)),
+            Err(_) => Err(ParseError::new(hex_digit, "expected a hex digit")),
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<Packet>> {
+    input
+        .lines()
+        .map(|bit_transmission| {
+            let binary_str = to_binary_str(bit_transmission)?;
+            let (parsed_packet, _) = Packet::parse(&binary_str)
+                .ok_or_else(|| ParseError::missing(bit_transmission, "the rest of the packet"))?;
+            Ok(parsed_packet)
+        })
+        .collect()
+}
+
+pub struct Day16;
+
+// Each line is its own transmission, so answer for each of them
+impl Solution for Day16 {
+    type Input = Vec<Packet>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, packets: &Self::Input) -> impl Display {
+        packets
+            .iter()
+            .map(|packet| {
+                let version_sum: usize = packet.versions().into_iter().map(usize::from).sum();
+                version_sum.to_string()
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn part2(&self, packets: &Self::Input) -> impl Display {
+        packets
+            .iter()
+            .map(|packet| packet.eval().to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    // Writes a packet back out as bits, the inverse of Packet::parse
+    fn to_bits(packet: &Packet) -> String {
+        let mut bits = format!("{:03b}{:03b}", packet.version, packet.type_id);
+        match &packet.payload {
+            Literal(val) => {
+                let num_groups = (usize::BITS - val.leading_zeros()).div_ceil(4).max(1);
+                for i in (0..num_groups).rev() {
+                    let more_groups = if i > 0 { '1' } else { '0' };
+                    bits.push(more_groups);
+                    bits.push_str(&format!("{:04b}", (val >> (i * 4)) & 0xF));
+                }
+            }
+            Operator {
+                length_type,
+                sub_packets,
+            } => {
+                match length_type {
+                    TotalBitLength(len) => bits.push_str(&format!("0{:015b}", len)),
+                    NumSubPackets(n) => bits.push_str(&format!("1{:011b}", n)),
+                }
+                for sub_packet in sub_packets {
+                    bits.push_str(&to_bits(sub_packet));
+                }
+            }
+        }
+        bits
+    }
+
+    // Pads the bits out to a whole number of hex digits, as the transmission does
+    fn to_hex(bits: &str) -> String {
+        let padded = format!("{:0<1$}", bits, bits.len().div_ceil(4) * 4);
+        padded
+            .as_bytes()
+            .chunks(4)
+            .map(|nibble| {
+                let nibble = std::str::from_utf8(nibble).unwrap();
+                format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+            })
+            .collect()
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let literal = (0u8..8, any::<usize>()).prop_map(|(version, val)| Packet {
+            version,
+            type_id: 4,
+            payload: Literal(val),
+        });
+        literal.prop_recursive(3, 24, 4, |inner| {
+            let type_id = prop::sample::select(vec![0u8, 1, 2, 3, 5, 6, 7]);
+            let sub_packets = prop::collection::vec(inner, 1..4);
+            (0u8..8, type_id, sub_packets, any::<bool>()).prop_map(
+                |(version, type_id, sub_packets, by_count)| {
+                    let length_type = if by_count {
+                        NumSubPackets(sub_packets.len())
+                    } else {
+                        TotalBitLength(sub_packets.iter().map(|p| to_bits(p).len()).sum())
+                    };
+                    Packet {
+                        version,
+                        type_id,
+                        payload: Operator {
+                            length_type,
+                            sub_packets,
+                        },
+                    }
+                },
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn packets_round_trip_through_hex(packet in packet()) {
+            let bits = to_bits(&packet);
+            let binary_str = to_binary_str(&to_hex(&bits)).unwrap();
+            let (parsed_packet, num_bits_read) = Packet::parse(&binary_str).unwrap();
+            prop_assert_eq!(parsed_packet, packet);
+            prop_assert_eq!(num_bits_read, bits.len());
+        }
+    }
+
+    #[test]
+    fn hex_to_binary_conversion() {
+        let tests = HashMap::from([
+            ("D2FE28", "110100101111111000101000"),
+            (
+                "38006F45291200",
+                "00111000000000000110111101000101001010010001001000000000",
+            ),
+            (
+                "EE00D40C823060",
+                "11101110000000001101010000001100100000100011000001100000",
+            ),
+        ]);
+        for (hex, bin) in tests {
+            let binary_str = to_binary_str(hex).unwrap();
+            assert_eq!(binary_str, bin);
+        }
+    }
+
+    #[test]
+    fn parse_literal_packet() {
+        let literal_packet_binary = "110100101111111000101000";
+        let (parsed_packet, num_bits_read) = Packet::parse(literal_packet_binary).unwrap();
+        let expected_packet = Packet {
+            version: 6,
+            type_id: 4,
+            payload: Literal(2021),
+        };
+        assert_eq!(parsed_packet, expected_packet);
+        assert_eq!(num_bits_read, literal_packet_binary.len() - 3);
+    }
+
+    #[test]
+    fn parse_operator_packet() {
+        let tests = HashMap::from([
+            (
+                "38006F45291200",
+                (
+                    Packet {
+                        version: 1,
+                        type_id: 6,
+                        payload: Operator {
+                            length_type: TotalBitLength(27),
+                            sub_packets: vec![
+                                Packet {
+                                    version: 6,
+                                    type_id: 4,
+                                    payload: Literal(10),
+                                },
+                                Packet {
+                                    version: 2,
+                                    type_id: 4,
+                                    payload: Literal(20),
+                                },
+                            ],
+                        },
+                    },
+                    49,
+                ),
+            ),
+            (
+                "EE00D40C823060",
+                (
+                    Packet {
+                        version: 7,
+                        type_id: 3,
+                        payload: Operator {
+                            length_type: NumSubPackets(3),
+                            sub_packets: vec![
+                                Packet {
+                                    version: 2,
+                                    type_id: 4,
+                                    payload: Literal(1),
+                                },
+                                Packet {
+                                    version: 4,
+                                    type_id: 4,
+                                    payload: Literal(2),
+                                },
+                                Packet {
+                                    version: 1,
+                                    type_id: 4,
+                                    payload: Literal(3),
+                                },
+                            ],
+                        },
+                    },
+                    51,
+                ),
+            ),
+        ]);
+
+        for (hex, (expected_packet, expected_bits_read)) in tests {
+            println!("testing parsing '{}' into packets...", hex);
+            let binary_str = to_binary_str(hex).unwrap();
+            let (parsed_packet, num_bits_read) = Packet::parse(&binary_str).unwrap();
+            assert_eq!(parsed_packet, expected_packet);
+            assert_eq!(num_bits_read, expected_bits_read);
+        }
+    }
+
+    #[test]
+    fn sum_version_nums() {
+        let tests = HashMap::from([
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+            ("A0016C880162017C3686B18A3D4780", 31),
+        ]);
+        for (hex, expected_version_sum) in tests {
+            println!("summing version nums for transmission '{}'...", hex);
+            let binary_str = to_binary_str(hex).unwrap();
+            let (parsed_packet, _) = Packet::parse(&binary_str).unwrap();
+            let version_sum: usize = parsed_packet.versions().into_iter().map(usize::from).sum();
+            assert_eq!(version_sum, expected_version_sum);
+        }
+    }
+
+    #[test]
+    fn eval_packets() {
+        let tests = HashMap::from([
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ]);
+        for (hex, expected_val) in tests {
+            println!("evaluating packet '{}'...", hex);
+            let binary_str = to_binary_str(hex).unwrap();
+            let (parsed_packet, _) = Packet::parse(&binary_str).unwrap();
+            let val = parsed_packet.eval();
+            assert_eq!(val, expected_val);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_core::geom::{Aabb, Point2, Vec2};
+use aoc_core::parse::{parse_token, split_once, strip_prefix};
+use aoc_core::Solution;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+type Area = Aabb<2>;
+type KineticState = (Point2, Vec2);
+
+fn past_area(area: &Area, pos: &Point2) -> bool {
+    pos.x > area.max[0] || pos.y < area.min[1]
+}
+
+fn step((pos, vel): &mut KineticState) {
+    //  The probe's x position increases by its x velocity.
+    //  The probe's y position increases by its y velocity.
+    //  Due to drag, the probe's x velocity changes by 1 toward the value 0; that is, it decreases by 1 if it is greater than 0, increases by 1 if it is less than 0, or does not change if it is already 0.
+    //  Due to gravity, the probe's y velocity decreases by 1.
+
+    *pos += *vel;
+    *vel += Vec2::new(0.cmp(&vel.x) as isize, -1);
+}
+
+fn launch_probe(area: &Area, mut state: KineticState) -> bool {
+    while !past_area(area, &state.0) {
+        if area.contains(state.0) {
+            return true;
+        }
+        step(&mut state);
+    }
+    false
+}
+
+const X_VELS: std::ops::Range<isize> = 0..1000;
+
+// Every initial velocity with the given x velocity that hits the target area
+fn accurate_initial_vels_with_x(
+    target_area: &Area,
+    x_vel: isize,
+) -> impl Iterator<Item = Vec2> + '_ {
+    let max_y_vel = 1000;
+    (-500..max_y_vel)
+        .map(move |y_vel| Vec2::new(x_vel, y_vel))
+        .filter(|init_vel| launch_probe(target_area, (Point2::ORIGIN, *init_vel)))
+}
+
+#[cfg(not(feature = "parallel"))]
+fn accurate_initial_vels(target_area: &Area) -> Vec<Vec2> {
+    X_VELS
+        .flat_map(|x_vel| accurate_initial_vels_with_x(target_area, x_vel))
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+fn accurate_initial_vels(target_area: &Area) -> Vec<Vec2> {
+    X_VELS
+        .into_par_iter()
+        .flat_map_iter(|x_vel| accurate_initial_vels_with_x(target_area, x_vel))
+        .collect()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Area;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, target_area: &Self::Input) -> impl Display {
+        // The probe's y velocity drops by 1 each step, so the highest point is
+        // reached after climbing y_vel + (y_vel - 1) + ... + 1
+        let max_y_vel = accurate_initial_vels(target_area)
+            .into_iter()
+            .map(|vel| vel.y)
+            .max()
+            .unwrap();
+        if max_y_vel > 0 {
+            max_y_vel * (max_y_vel + 1) / 2
+        } else {
+            0
+        }
+    }
+
+    fn part2(&self, target_area: &Self::Input) -> impl Display {
+        accurate_initial_vels(target_area).len()
+    }
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Area> {
+    let ranges = strip_prefix(input.trim_end(), "target area: ")?;
+    let (x_range, y_range) = split_once(ranges, ", ")?;
+    Ok(Aabb::from([
+        parse_range(x_range, "x=")?,
+        parse_range(y_range, "y=")?,
+    ]))
+}
+
+fn parse_range(s: &str, prefix: &str) -> aoc_core::Result<RangeInclusive<isize>> {
+    let (start, end) = split_once(strip_prefix(s, prefix)?, "..")?;
+    Ok(parse_token(start)?..=parse_token(end)?)
+}
@@ -0,0 +1,229 @@
+use aoc_core::geom::{Point3, Rotation, Vec3};
+use aoc_core::parse::{parse_token, split_once};
+use aoc_core::{ParseError, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+type Position = Point3;
+type Vector = Vec3;
+
+// Scanners overlap where they both see at least this many of the same beacons
+const MIN_OVERLAPPING_BEACONS: usize = 12;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Vec<Position>>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, scanner_beacons: &Self::Input) -> impl Display {
+        let (_scanner_positions, known_beacons) = locate_scanners(scanner_beacons);
+        known_beacons.len()
+    }
+
+    fn part2(&self, scanner_beacons: &Self::Input) -> impl Display {
+        let (scanner_positions, _known_beacons) = locate_scanners(scanner_beacons);
+        scanner_positions
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.manhattan_distance(*b))
+            .max()
+            .unwrap()
+    }
+}
+
+// Returns the positions of every scanner and every beacon, relative to scanner 0
+fn locate_scanners(
+    relative_beacon_positions_to_scanners: &[Vec<Position>],
+) -> (Vec<Position>, HashSet<Position>) {
+    let num_scanners = relative_beacon_positions_to_scanners.len();
+
+    // If we store the vectors (slope, magnitude) that describe the difference in space in a given scanner's set of Positions,
+    // we should be able to intersect those vectors against some other scanner's positions in some orientation.
+    // If we find at least 12 vectors in common, then we know that those vectors correspond to the _same_ position in space.
+    // We should probably build up a set of "absolute" points as we go, i.e. all points relative to scanner 0.
+    // "True" cardinality can be set by scanner 0's orientation, other scanner's beacon's vectors can be translated through different orientations until a match is found.
+
+    // we only know the "correct" vector mappings of scanner 0 at the start, since that's our defined "absolute" orientation
+    let scanner0_beacons = relative_beacon_positions_to_scanners.first().unwrap();
+    let mut scanner_vectors_in_absolute_orientation: Vec<HashMap<Vector, [Position; 2]>> =
+        vec![HashMap::new(); num_scanners];
+    scanner_vectors_in_absolute_orientation[0] = vectors(scanner0_beacons);
+
+    let mut all_beacon_positions: Vec<Vec<Vec<Position>>> = vec![];
+    for relative_beacons in relative_beacon_positions_to_scanners.iter() {
+        let scanner_beacon_positions: Vec<Vec<Position>> = transpose(
+            relative_beacons
+                .iter()
+                .map(|p| all_orientations(p).collect())
+                .collect(),
+        );
+        all_beacon_positions.push(scanner_beacon_positions);
+    }
+
+    let mut known_beacons: HashSet<Position> = HashSet::from_iter(scanner0_beacons.iter().cloned());
+    let mut known_scanner_idxs: HashSet<usize> = HashSet::new();
+    known_scanner_idxs.insert(0);
+    let mut scanner_positions: Vec<Option<Position>> =
+        Vec::from_iter(relative_beacon_positions_to_scanners.iter().map(|_| None));
+    scanner_positions[0] = Some(Point3::ORIGIN);
+
+    while scanner_positions.iter().any(|p| p.is_none()) {
+        for unknown_scanner_idx in
+            scanner_positions
+                .clone()
+                .iter()
+                .enumerate()
+                .filter_map(|(i, p)| match p {
+                    None => Some(i),
+                    Some(_) => None,
+                })
+        {
+            find_intersecting_scanner(
+                unknown_scanner_idx,
+                &all_beacon_positions,
+                &mut scanner_vectors_in_absolute_orientation,
+                &mut scanner_positions,
+                &mut known_beacons,
+            );
+        }
+    }
+
+    let scanner_positions = scanner_positions.into_iter().map(Option::unwrap).collect();
+    (scanner_positions, known_beacons)
+}
+
+fn find_intersecting_scanner(
+    target_scanner_idx: usize,
+    all_beacon_positions: &[Vec<Vec<Position>>],
+    scanner_vectors_in_absolute_orientation: &mut [HashMap<Vector, [Position; 2]>],
+    scanner_positions: &mut [Option<Position>],
+    known_beacons: &mut HashSet<Position>,
+) {
+    for target_beacons in all_beacon_positions.get(target_scanner_idx).unwrap() {
+        let target_scanner_vector_mapping = vectors(target_beacons);
+        let target_scanner_vectors: HashSet<&Vector> =
+            target_scanner_vector_mapping.keys().collect();
+
+        for (source_scanner_idx, source_scanner_pos) in scanner_positions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pos)| pos.map(|p| (i, p)))
+        {
+            let source_scanner_vector_mapping = scanner_vectors_in_absolute_orientation
+                .get(source_scanner_idx)
+                .unwrap();
+
+            let source_scanner_vectors: HashSet<&Vector> =
+                source_scanner_vector_mapping.keys().collect();
+
+            let vector_intersection: HashSet<&Vector> = source_scanner_vectors
+                .intersection(&target_scanner_vectors)
+                .cloned()
+                .collect();
+
+            let matching_positions: HashSet<Position> = vector_intersection
+                .clone()
+                .into_iter()
+                .flat_map(|v| source_scanner_vector_mapping[v])
+                .collect();
+
+            // overlaps if more than 12 matching positions
+            if matching_positions.len() >= MIN_OVERLAPPING_BEACONS {
+                // one vector should yield positions in both scanners, diff between positions is the diff between scanners
+                let v = vector_intersection.into_iter().next().unwrap();
+                let beacon_source = source_scanner_vector_mapping[v][0];
+                let beacon_target = target_scanner_vector_mapping[v][0];
+                let target_scanner_pos = source_scanner_pos + (beacon_source - beacon_target);
+
+                // Set "absolute" scanner position
+                scanner_positions[target_scanner_idx] = Some(target_scanner_pos);
+
+                // Save vectors between beacons of scanner in "absolute" orientation
+                scanner_vectors_in_absolute_orientation[target_scanner_idx] =
+                    target_scanner_vector_mapping;
+
+                // TODO: translate from current pos using source scanner pos as diff vector
+                let target_beacons_relative_to_scanner_0: Vec<Position> = target_beacons
+                    .iter()
+                    .map(|p| *p + target_scanner_pos.to_vec())
+                    .collect();
+
+                // TODO: translate in terms of scanner 0
+                // add beacons in "absolute" orientation translated to scanner 0 reference to known set
+                for tb in target_beacons_relative_to_scanner_0 {
+                    known_beacons.insert(tb);
+                }
+                return;
+            }
+        }
+    }
+}
+
+// can improve performance by returning position references as values, cloning at last second
+fn vectors(beacons: &[Position]) -> HashMap<Vector, [Position; 2]> {
+    beacons
+        .iter()
+        .cartesian_product(beacons.iter())
+        .filter(|(a, b)| a != b)
+        .map(|(a, b)| (*a - *b, [*a, *b]))
+        .collect()
+}
+
+fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    assert!(!v.is_empty());
+    let len = v[0].len();
+    let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .map(|n| n.next().unwrap())
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+// Every way the scanner could be facing, as the beacon's position from each one
+fn all_orientations(p: &Position) -> impl Iterator<Item = Position> {
+    let p = *p;
+    Rotation::all().into_iter().map(move |r| r * p)
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<Vec<Position>>> {
+    let scanner_beacons: Vec<_> = input
+        .split("\n\n")
+        .map(parse_scanner_beacons)
+        .collect::<aoc_core::Result<_>>()?;
+    // part 2 measures the distance between scanners
+    if scanner_beacons.len() < 2 {
+        return Err(ParseError::missing(input, "at least 2 scanners"));
+    }
+    Ok(scanner_beacons)
+}
+
+fn parse_scanner_beacons(beacons_str: &str) -> aoc_core::Result<Vec<Position>> {
+    let beacons: Vec<Position> = beacons_str
+        .lines()
+        .skip(1)
+        .map(|pos_str| {
+            let (x, yz) = split_once(pos_str, ",")?;
+            let (y, z) = split_once(yz, ",")?;
+            Ok(Point3::new(
+                parse_token(x)?,
+                parse_token(y)?,
+                parse_token(z)?,
+            ))
+        })
+        .collect::<aoc_core::Result<_>>()?;
+    // any fewer and the scanner can't be matched up with the others
+    if beacons.len() < MIN_OVERLAPPING_BEACONS {
+        let e = format!("at least {} beacons", MIN_OVERLAPPING_BEACONS);
+        return Err(ParseError::missing(beacons_str, &e));
+    }
+    Ok(beacons)
+}
@@ -0,0 +1,211 @@
+use aoc_core::grid::{Grid, InfiniteGrid};
+use aoc_core::image::{self, ExportImage, Rgb};
+use aoc_core::parse::char_tokens;
+use aoc_core::viz::{self, Cell, Color, Frame, Visualize};
+use aoc_core::{ParseError, Part, Solution};
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+type InfiniteImg = InfiniteGrid<char>;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Vec<char>, Grid<char>);
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (enhancement_algorithm, img): &Self::Input) -> impl Display {
+        let enhanced = enhance_times(enhancement_algorithm, img, num_enhance_times(Part::One));
+        count_lit_pixels(&enhanced)
+    }
+
+    fn part2(&self, (enhancement_algorithm, img): &Self::Input) -> impl Display {
+        let enhanced = enhance_times(enhancement_algorithm, img, num_enhance_times(Part::Two));
+        count_lit_pixels(&enhanced)
+    }
+}
+
+impl ExportImage for Day20 {
+    fn export_image(
+        &self,
+        part: Part,
+        (enhancement_algorithm, img): &Self::Input,
+        path: &Path,
+    ) -> io::Result<()> {
+        let enhanced = enhance_times(enhancement_algorithm, img, num_enhance_times(part));
+        image::save(path, &enhanced.grid, |&pixel| match pixel {
+            '#' => Rgb::WHITE,
+            _ => Rgb::BLACK,
+        })
+    }
+}
+
+fn num_enhance_times(part: Part) -> usize {
+    match part {
+        Part::One => 2,
+        Part::Two => 50,
+    }
+}
+
+#[derive(Clone)]
+struct ImageFrame {
+    times_enhanced: usize,
+    img: InfiniteImg,
+}
+
+impl Frame for ImageFrame {
+    fn draw(&self) -> Grid<Cell> {
+        self.img.grid.map(|&pixel| match pixel {
+            '#' => Cell::new('#', Color::White),
+            _ => Cell::new('.', Color::Gray),
+        })
+    }
+
+    fn caption(&self) -> String {
+        let lit_pixels = count_lit_pixels(&self.img);
+        format!(
+            "enhanced {} times: {} pixels lit, background {}",
+            self.times_enhanced, lit_pixels, self.img.background
+        )
+    }
+}
+
+impl Visualize for Day20 {
+    fn frames(
+        &self,
+        part: Part,
+        (enhancement_algorithm, img): &Self::Input,
+    ) -> impl Iterator<Item = impl Frame> {
+        let num_enhance_times = num_enhance_times(part);
+        let initial = ImageFrame {
+            times_enhanced: 0,
+            img: InfiniteImg::new(img.clone(), '.'),
+        };
+        viz::steps(initial, move |frame| {
+            frame.img = enhance(&frame.img, enhancement_algorithm);
+            frame.times_enhanced += 1;
+            frame.times_enhanced < num_enhance_times
+        })
+    }
+}
+
+fn enhance_times(
+    enhancement_algorithm: &[char],
+    img: &Grid<char>,
+    num_enhance_times: usize,
+) -> InfiniteImg {
+    let mut inf_img = InfiniteImg::new(img.clone(), '.');
+    for _ in 0..num_enhance_times {
+        inf_img = enhance(&inf_img, enhancement_algorithm);
+    }
+    inf_img
+}
+
+fn count_lit_pixels(img: &InfiniteImg) -> usize {
+    img.grid.values().filter(|c| **c == '#').count()
+}
+
+fn enhance(img: &InfiniteImg, enhancement_algorithm: &[char]) -> InfiniteImg {
+    // the output grows by a pixel on every side, so it's shifted one down and right of the input
+    let output_rows = img.grid.rows() + 2;
+    let output_cols = img.grid.cols() + 2;
+
+    let output_img = Grid::from_fn(output_rows, output_cols, |pos| {
+        let (x, y) = pos.signed();
+        let serialized_pixels: String = (x - 2..=x)
+            .flat_map(|ix| (y - 2..=y).map(move |iy| *img.get((ix, iy))))
+            .collect();
+
+        enhancement_algorithm[enhancement_algorithm_idx(&serialized_pixels)]
+    });
+
+    // the infinite background is all the same pixel, so it all enhances to the same pixel too
+    let background_idx = if img.background == '#' { 511 } else { 0 };
+    InfiniteImg::new(output_img, enhancement_algorithm[background_idx])
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<(Vec<char>, Grid<char>)> {
+    let (enhancement_str, img_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::missing(input, "an image after a blank line"))?;
+
+    let enhancement_code = parse_pixels(enhancement_str)?;
+    if enhancement_code.len() != 512 {
+        return Err(ParseError::new(
+            enhancement_str,
+            "expected 512 pixels in enhancement algorithm",
+        ));
+    }
+
+    let img = parse_img_str(img_str)?;
+
+    Ok((enhancement_code, img))
+}
+
+fn parse_img_str(img_str: &str) -> aoc_core::Result<Grid<char>> {
+    Grid::parse(img_str, parse_pixel)
+}
+
+fn parse_pixels(s: &str) -> aoc_core::Result<Vec<char>> {
+    char_tokens(s).map(parse_pixel).collect()
+}
+
+fn parse_pixel(c: &str) -> aoc_core::Result<char> {
+    match c {
+        "#" => Ok('#'),
+        "." => Ok('.'),
+        _ => Err(ParseError::new(c, "expected `#` or `.`")),
+    }
+}
+
+fn enhancement_algorithm_idx(pixels: &str) -> usize {
+    let binary_string: String = pixels
+        .chars()
+        .map(|c| if c == '#' { '1' } else { '0' })
+        .collect();
+    usize::from_str_radix(binary_string.as_str(), 2).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_enhancement_algorithm_idx() {
+        let idx = enhancement_algorithm_idx("...#...#.");
+        assert_eq!(idx, 34);
+    }
+
+    #[test]
+    fn test_enhance() {
+        let enhancement = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+
+        let input_str = "#..#.
+#....
+##..#
+..#..
+..###";
+
+        let expected_output_str = ".##.##.
+#..#.#.
+##.#..#
+####..#
+.#..##.
+..##..#
+...#.#.";
+
+        let input_img = InfiniteImg::new(parse_img_str(input_str).unwrap(), '.');
+        let expected_output_img =
+            InfiniteImg::new(parse_img_str(expected_output_str).unwrap(), '.');
+
+        let actual_output_img = enhance(
+            &input_img,
+            enhancement.chars().collect::<Vec<char>>().as_slice(),
+        );
+        assert_eq!(actual_output_img, expected_output_img);
+    }
+}
@@ -0,0 +1,168 @@
+use aoc_core::geom::Aabb;
+use aoc_core::parse::{parse_token, split_once, strip_prefix};
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+type Cuboid = Aabb<3>;
+type RebootInstruction = (bool, Cuboid);
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<RebootInstruction>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> impl Display {
+        let (positive_regions, negative_regions) = reboot(instructions);
+
+        let init_cuboid = Cuboid::from([-50..=50, -50..=50, -50..=50]);
+        let regions_in_init = |regions: &[Cuboid]| -> Vec<Cuboid> {
+            regions
+                .iter()
+                .filter_map(|r| r.intersection(&init_cuboid))
+                .collect()
+        };
+
+        let positive_volume_in_init = sum_volumes(&regions_in_init(&positive_regions));
+        let negative_volume_in_init = sum_volumes(&regions_in_init(&negative_regions));
+        positive_volume_in_init - negative_volume_in_init
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> impl Display {
+        let (positive_regions, negative_regions) = reboot(instructions);
+        sum_volumes(&positive_regions) - sum_volumes(&negative_regions)
+    }
+}
+
+// Maintain two sets: positive regions and negative regions:
+// Accumulate intersections with positive regions, add intersections to negative regions
+// Accumulate intersections with negative regions, add intersections to positive regions
+// If a cuboid region is turning on, then
+//      - Add cuboid to positive region
+fn reboot(instructions: &[RebootInstruction]) -> (Vec<Cuboid>, Vec<Cuboid>) {
+    let mut positive_regions: Vec<Cuboid> = Vec::new();
+    let mut negative_regions: Vec<Cuboid> = Vec::new();
+
+    for (toggle, cuboid) in instructions {
+        let agg_intersections = |regions: &Vec<Cuboid>| {
+            regions
+                .iter()
+                .filter_map(|pr| cuboid.intersection(pr))
+                .collect()
+        };
+
+        let positive_intersections: Vec<Cuboid> = agg_intersections(&positive_regions);
+        let negative_intersections: Vec<Cuboid> = agg_intersections(&negative_regions);
+
+        negative_regions.extend(positive_intersections);
+        positive_regions.extend(negative_intersections);
+
+        if *toggle {
+            positive_regions.push(*cuboid);
+        }
+    }
+
+    (positive_regions, negative_regions)
+}
+
+fn sum_volumes(regions: &[Cuboid]) -> usize {
+    regions.iter().map(Cuboid::volume).sum()
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<RebootInstruction>> {
+    input
+        .lines()
+        .map(|s| {
+            let (toggle, ranges) = split_once(s, " ")?;
+            let toggle: bool = match toggle {
+                "on" => true,
+                "off" => false,
+                x => return Err(ParseError::new(x, "expected `on` or `off`")),
+            };
+
+            let (x_range, yz_ranges) = split_once(ranges, ",")?;
+            let (y_range, z_range) = split_once(yz_ranges, ",")?;
+            Ok((
+                toggle,
+                Cuboid::from([
+                    parse_range(x_range, "x=")?,
+                    parse_range(y_range, "y=")?,
+                    parse_range(z_range, "z=")?,
+                ]),
+            ))
+        })
+        .collect()
+}
+
+fn parse_range(s: &str, prefix: &str) -> aoc_core::Result<RangeInclusive<isize>> {
+    let (start, end) = split_once(strip_prefix(s, prefix)?, "..")?;
+    Ok(parse_token(start)?..=parse_token(end)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    fn cube(r: RangeInclusive<isize>) -> Cuboid {
+        Cuboid::from([r.clone(), r.clone(), r])
+    }
+
+    #[test]
+    fn test_intersection() {
+        let tests = HashMap::from([
+            // non-overlapping
+            ((cube(0..=2), cube(3..=6)), None),
+            // overlap one voxel
+            ((cube(0..=2), cube(2..=3)), Some(cube(2..=2))),
+            // overlap edge
+            (
+                (cube(0..=3), Cuboid::from([3..=6, 0..=3, 0..=3])),
+                Some(Cuboid::from([3..=3, 0..=3, 0..=3])),
+            ),
+            // second greater
+            ((cube(0..=3), cube(1..=4)), Some(cube(1..=3))),
+            // first greater
+            ((cube(1..=4), cube(0..=3)), Some(cube(1..=3))),
+            // first encapsulated
+            ((cube(0..=5), cube(1..=3)), Some(cube(1..=3))),
+            // second encapsulated
+            ((cube(1..=3), cube(0..=5)), Some(cube(1..=3))),
+        ]);
+        for ((a, b), expected_intersection) in tests {
+            println!("{:?}", (&a, &b));
+            let actual_intersection = a.intersection(&b);
+            assert_eq!(actual_intersection, expected_intersection);
+        }
+    }
+
+    // Small boxes near the origin, so plenty of pairs overlap
+    fn cuboid() -> impl Strategy<Value = Cuboid> {
+        prop::array::uniform3((-10isize..10, -10isize..10))
+            .prop_map(|ranges| Cuboid::from(ranges.map(|(a, b)| a.min(b)..=a.max(b))))
+    }
+
+    proptest! {
+        #[test]
+        fn intersection_is_commutative(a in cuboid(), b in cuboid()) {
+            prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+
+        #[test]
+        fn intersection_is_contained(a in cuboid(), b in cuboid()) {
+            match a.intersection(&b) {
+                Some(i) => {
+                    prop_assert!(a.contains_box(&i) && b.contains_box(&i));
+                    prop_assert!(i.volume() <= a.volume().min(b.volume()));
+                }
+                // so neither box can have a corner inside the other
+                None => prop_assert!(!a.contains(b.min) && !a.contains(b.max) && !b.contains(a.min)),
+            }
+        }
+    }
+}
@@ -0,0 +1,643 @@
+use crate::BurrowLocation::{Hallway, Room};
+use aoc_core::geom::Point2;
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::search::{self, Search};
+use aoc_core::viz::{Cell, Color, Frame, Visualize};
+use aoc_core::{ParseError, Part, Solution};
+use lazy_static::lazy_static;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt::Display;
+use tracing::trace;
+
+lazy_static! {
+    static ref ORGANIZED_SIDE_ROOMS: [Vec<Amphipod>; 4] = [
+        vec![Amphipod::A, Amphipod::A, Amphipod::A, Amphipod::A],
+        vec![Amphipod::B, Amphipod::B, Amphipod::B, Amphipod::B],
+        vec![Amphipod::C, Amphipod::C, Amphipod::C, Amphipod::C],
+        vec![Amphipod::D, Amphipod::D, Amphipod::D, Amphipod::D],
+    ];
+    static ref STARTING_TEST_SIDE_ROOMS: [Vec<Amphipod>; 4] = [
+        vec![Amphipod::A, Amphipod::B],
+        vec![Amphipod::D, Amphipod::C],
+        vec![Amphipod::C, Amphipod::B],
+        vec![Amphipod::A, Amphipod::D],
+    ];
+    static ref AMPHIPOD_ENERGY_COSTS: HashMap<Amphipod, usize> = HashMap::from([
+        (Amphipod::A, 1),
+        (Amphipod::B, 10),
+        (Amphipod::C, 100),
+        (Amphipod::D, 1000)
+    ]);
+}
+
+const ORGANIZED_AMPHIPOD_ROOMS: [Amphipod; 4] =
+    [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::D];
+const VALID_HALLWAY_IDXS: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
+
+type Position = Point2;
+
+// Amphipods have to step out into the hallway (y = 0) to go anywhere, so the way from one place
+// to another is through the spot in the hallway above where they're going
+fn walking_distance(from: &Position, to: &Position) -> usize {
+    let above_to = Point2::new(to.x, 0);
+    from.manhattan_distance(above_to) + above_to.manhattan_distance(*to)
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Amphipod {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Amphipod {
+    fn letter(self) -> char {
+        match self {
+            Amphipod::A => 'A',
+            Amphipod::B => 'B',
+            Amphipod::C => 'C',
+            Amphipod::D => 'D',
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Amphipod::A => Color::Yellow,
+            Amphipod::B => Color::Green,
+            Amphipod::C => Color::Cyan,
+            Amphipod::D => Color::Magenta,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+struct Burrow {
+    hallways: [Option<Amphipod>; 11],
+    // room is stack
+    rooms: [Vec<Amphipod>; 4],
+    room_size: usize,
+}
+
+impl Burrow {
+    fn new(rooms: [Vec<Amphipod>; 4]) -> Self {
+        let room_size = rooms[0].len();
+        Burrow {
+            hallways: Default::default(),
+            rooms,
+            room_size,
+        }
+    }
+
+    fn apply_move(&self, amphipod_move: &Move) -> Self {
+        let mut burrow_clone = self.clone();
+        amphipod_move.apply(&mut burrow_clone);
+        burrow_clone
+    }
+
+    fn is_organized(&self) -> bool {
+        (0..4).all(|room_idx| self.room_is_organized(room_idx))
+    }
+
+    fn reachable_hallways(&self, room_idx: usize) -> Vec<usize> {
+        let room_hallway_idx = 2 + 2 * room_idx;
+        assert!(!VALID_HALLWAY_IDXS.contains(&room_hallway_idx));
+
+        let blocked_left: Option<usize> = (0..room_hallway_idx)
+            .rev()
+            .find(|i| self.hallways[*i].is_some());
+        let blocked_right: Option<usize> =
+            ((room_hallway_idx + 1)..11).find(|i| self.hallways[*i].is_some());
+
+        let valid_left = match blocked_left {
+            None => 0,
+            // will never be blocked passed 7 to the left
+            Some(blocked_hallway_idx) => {
+                if blocked_hallway_idx == 0 {
+                    1
+                } else {
+                    blocked_hallway_idx + 2
+                }
+            }
+        };
+
+        let valid_right = match blocked_right {
+            None => 10,
+            // will never be blocked passed 3 to the right
+            Some(blocked_hallway_idx) => {
+                if blocked_hallway_idx == 10 {
+                    9
+                } else {
+                    blocked_hallway_idx - 2
+                }
+            }
+        };
+
+        VALID_HALLWAY_IDXS
+            .iter()
+            .filter(|&&i| {
+                (i >= valid_left && i < room_hallway_idx)
+                    || (i > room_hallway_idx && i <= valid_right)
+            })
+            .copied()
+            .collect()
+    }
+
+    fn room_is_organized(&self, room_idx: usize) -> bool {
+        let expected_amphipod = ORGANIZED_AMPHIPOD_ROOMS[room_idx];
+        let room = self.rooms.get(room_idx).unwrap();
+        room.len() == self.room_size && room.iter().all(|a| a == &expected_amphipod)
+    }
+
+    fn moves(&self) -> Vec<Move> {
+        let mut valid_next_moves: Vec<Move> = vec![];
+
+        for unorganized_room_idx in (0..4).filter(|&i| !self.room_is_organized(i)) {
+            let from_location = Room(unorganized_room_idx);
+            let unorganized_room = self.rooms.get(unorganized_room_idx).unwrap();
+            if let Some(moving_amphipod) = unorganized_room.last() {
+                // room to hallway
+                let reachable_hallway_idxs = self.reachable_hallways(unorganized_room_idx);
+                let hallway_moves: Vec<Move> = reachable_hallway_idxs
+                    .iter()
+                    .map(|hidx| Move {
+                        from: from_location,
+                        to: Hallway(*hidx),
+                    })
+                    .collect();
+                valid_next_moves.extend(hallway_moves);
+
+                // room to room
+                let destination_room_idx = ORGANIZED_AMPHIPOD_ROOMS
+                    .iter()
+                    .position(|organized_amph| organized_amph == moving_amphipod)
+                    .unwrap();
+
+                // if you're in the right room, no need to move
+                if destination_room_idx == unorganized_room_idx {
+                    continue;
+                }
+
+                let destination_room = self.rooms.get(destination_room_idx).unwrap();
+                // path must not be blocked and destination room must have space and not contain other types of amphipods
+                let unorganized_room_hallway_idx = 2 + 2 * unorganized_room_idx;
+                let destination_room_hallway_idx = 2 + 2 * destination_room_idx;
+
+                let min_hidx = min(unorganized_room_hallway_idx, destination_room_hallway_idx);
+                let max_hidx = max(unorganized_room_hallway_idx, destination_room_hallway_idx);
+
+                let path_to_dest_room_blocked = self
+                    .hallways
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(hallway_idx, amph)| amph.map(|_| hallway_idx))
+                    .any(|hallway_idx| hallway_idx > min_hidx && hallway_idx < max_hidx);
+
+                if !path_to_dest_room_blocked
+                    && destination_room.len() < self.room_size
+                    && destination_room.iter().all(|a| a == moving_amphipod)
+                {
+                    valid_next_moves.push(Move {
+                        from: from_location,
+                        to: Room(destination_room_idx),
+                    })
+                }
+            }
+        }
+
+        // hallway to room
+        for (current_hallway_idx, moving_amphipod) in self
+            .hallways
+            .iter()
+            .enumerate()
+            .filter_map(|(hidx, amph)| amph.map(|a| (hidx, a)))
+        {
+            let destination_room_idx = ORGANIZED_AMPHIPOD_ROOMS
+                .iter()
+                .position(|organized_amph| organized_amph == &moving_amphipod)
+                .unwrap();
+
+            let destination_room = self.rooms.get(destination_room_idx).unwrap();
+            // path must not be blocked and destination room must have space and not contain other types of amphipods
+            let destination_room_hallway_idx = 2 + 2 * destination_room_idx;
+
+            let min_hidx = min(current_hallway_idx, destination_room_hallway_idx);
+            let max_hidx = max(current_hallway_idx, destination_room_hallway_idx);
+
+            let path_to_dest_room_blocked = self
+                .hallways
+                .iter()
+                .enumerate()
+                .filter_map(|(hidx, amph)| amph.map(|_| hidx))
+                .any(|hallway_idx| hallway_idx > min_hidx && hallway_idx < max_hidx);
+
+            if !path_to_dest_room_blocked
+                && destination_room.len() < self.room_size
+                && destination_room.iter().all(|a| a == &moving_amphipod)
+            {
+                valid_next_moves.push(Move {
+                    from: Hallway(current_hallway_idx),
+                    to: Room(destination_room_idx),
+                })
+            }
+        }
+
+        valid_next_moves
+    }
+}
+
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+enum BurrowLocation {
+    Hallway(usize),
+    Room(usize),
+}
+
+impl BurrowLocation {
+    fn valid(&self) -> bool {
+        match self {
+            BurrowLocation::Hallway(i) => ![2, 4, 6, 8].contains(i),
+            BurrowLocation::Room(i) => i < &4,
+        }
+    }
+
+    fn is_open(&self, burrow: &Burrow) -> bool {
+        match self {
+            BurrowLocation::Hallway(i) => burrow.hallways[*i].is_none(),
+            BurrowLocation::Room(i) => burrow.rooms[*i].len() < burrow.room_size,
+        }
+    }
+
+    fn get_amphipod(&self, burrow: &Burrow) -> Option<Amphipod> {
+        match self {
+            BurrowLocation::Hallway(i) => burrow.hallways[*i],
+            BurrowLocation::Room(i) => burrow.rooms[*i].last().copied(),
+        }
+    }
+
+    fn position(&self, burrow: &Burrow) -> Position {
+        match self {
+            BurrowLocation::Hallway(i) => Point2::new(*i as isize, 0),
+            // 0 => 2, 1 => 4, 2 => 6, 3 => 8
+            BurrowLocation::Room(i) => Point2::new(
+                (2 + 2 * i) as isize,
+                ((burrow.room_size + 1) - burrow.rooms[*i].len()) as isize,
+            ),
+        }
+    }
+}
+
+// Note that movement from and to the same Position variant is disallowed.
+// E.g. moving from one hallway location to another, since amphipods will only move out of their rooms once before spending their other move going into a room.
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+struct Move {
+    from: BurrowLocation,
+    to: BurrowLocation,
+}
+
+impl Move {
+    fn is_valid(&self, burrow: &Burrow) -> bool {
+        let hallway_to_hallway = matches!((self.from, self.to), (Hallway(_), Hallway(_)));
+
+        self.from.valid() && self.to.valid() && self.to.is_open(burrow) && !hallway_to_hallway
+    }
+
+    fn cost(&self, burrow: &Burrow) -> usize {
+        let amphipod = self.from.get_amphipod(burrow).unwrap();
+        let from_pos = self.from.position(burrow);
+        // let to_pos = self.to.position(burrow);
+        let to_pos = match self.to {
+            BurrowLocation::Hallway(i) => Point2::new(i as isize, 0),
+            // 0 => 2, 1 => 4, 2 => 6, 3 => 8
+            BurrowLocation::Room(i) => Point2::new(
+                (2 + 2 * i) as isize,
+                (burrow.room_size - burrow.rooms[i].len()) as isize,
+            ),
+        };
+
+        AMPHIPOD_ENERGY_COSTS[&amphipod] * walking_distance(&from_pos, &to_pos)
+    }
+
+    fn apply(&self, burrow: &mut Burrow) {
+        if !self.is_valid(burrow) {
+            panic!("AAAAAAAAAA");
+        }
+        let amphipod = match self.from {
+            Hallway(i) => {
+                let amph = burrow.hallways[i];
+                burrow.hallways[i] = None;
+                amph
+            }
+            Room(i) => burrow.rooms.get_mut(i).unwrap().pop(),
+        }
+        .unwrap();
+
+        match self.to {
+            Hallway(i) => {
+                assert!(burrow.hallways[i].is_none());
+                burrow.hallways[i] = Some(amphipod);
+            }
+            Room(i) => burrow.rooms.get_mut(i).unwrap().push(amphipod),
+        };
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = [Vec<Amphipod>; 4];
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, rooms: &Self::Input) -> impl Display {
+        least_energy_to_organize(rooms.clone())
+    }
+
+    fn part2(&self, rooms: &Self::Input) -> impl Display {
+        least_energy_to_organize(unfold(rooms))
+    }
+}
+
+// The burrow as drawn in the puzzle, after some of the moves that organize it for the least energy
+struct BurrowFrame {
+    burrow: Burrow,
+    energy: usize,
+    moves: usize,
+}
+
+impl Frame for BurrowFrame {
+    fn draw(&self) -> Grid<Cell> {
+        let room_size = self.burrow.room_size;
+        let amphipod = |a: Option<Amphipod>| match a {
+            Some(a) => Cell::new(a.letter(), a.color()),
+            None => Cell::plain('.'),
+        };
+        let wall = Cell::new('#', Color::Gray);
+
+        Grid::from_fn(room_size + 3, 13, |Pos { row, col }| match (row, col) {
+            (1, 1..=11) => amphipod(self.burrow.hallways[col - 1]),
+            (1, _) => wall,
+            // rooms are stacks, so the top of the diagram is the end of the room
+            (2.., 3 | 5 | 7 | 9) if row < room_size + 2 => {
+                let room = &self.burrow.rooms[(col - 3) / 2];
+                amphipod(room.get(room_size + 1 - row).copied())
+            }
+            // below the hallway, the walls only go around the rooms
+            (3.., 0 | 1 | 11 | 12) => Cell::plain(' '),
+            _ => wall,
+        })
+    }
+
+    fn caption(&self) -> String {
+        format!("{} moves: {} energy", self.moves, self.energy)
+    }
+}
+
+impl Visualize for Day23 {
+    fn frames(&self, part: Part, rooms: &Self::Input) -> impl Iterator<Item = impl Frame> {
+        let rooms = match part {
+            Part::One => rooms.clone(),
+            Part::Two => unfold(rooms),
+        };
+        let search = organize(rooms);
+        let path = search.path().expect("not solvable");
+        path.into_iter()
+            .enumerate()
+            .map(move |(moves, burrow)| BurrowFrame {
+                energy: search.distance(&burrow).unwrap(),
+                burrow,
+                moves,
+            })
+    }
+}
+
+// The diagram folds out into two more rows between the first and last rows of each room:
+//   #D#C#B#A#
+//   #D#B#A#C#
+// Diagrams that have already been unfolded are left as they are
+fn unfold(rooms: &[Vec<Amphipod>; 4]) -> [Vec<Amphipod>; 4] {
+    let unfolded_rows = [
+        [Amphipod::D, Amphipod::C, Amphipod::B, Amphipod::A],
+        [Amphipod::D, Amphipod::B, Amphipod::A, Amphipod::C],
+    ];
+    if rooms[0].len() == UNFOLDED_ROOM_SIZE {
+        return rooms.clone();
+    }
+    [0, 1, 2, 3].map(|room_idx| {
+        let room = &rooms[room_idx];
+        vec![
+            room[0],
+            unfolded_rows[1][room_idx],
+            unfolded_rows[0][room_idx],
+            room[1],
+        ]
+    })
+}
+
+fn least_energy_to_organize(rooms: [Vec<Amphipod>; 4]) -> usize {
+    organize(rooms).cost().expect("not solvable")
+}
+
+// Searches moves from the starting burrow until it's organized
+fn organize(rooms: [Vec<Amphipod>; 4]) -> Search<Burrow> {
+    let burrow = Burrow::new(rooms);
+
+    let get_neighbors = |burrow: &Burrow| {
+        burrow
+            .moves()
+            .iter()
+            .map(|mv| {
+                trace!(?mv);
+                (burrow.apply_move(mv), mv.cost(burrow))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let is_finished = |burrow: &Burrow| burrow.is_organized();
+
+    search::dijkstra(burrow, get_neighbors, is_finished)
+}
+
+// How deep the side rooms are in the diagram as given, and once it's unfolded for part 2
+const FOLDED_ROOM_SIZE: usize = 2;
+const UNFOLDED_ROOM_SIZE: usize = 4;
+
+// Rooms are stacks, so they're read from the bottom of the diagram up
+fn parse_input(input: &str) -> aoc_core::Result<[Vec<Amphipod>; 4]> {
+    let lines: Vec<&str> = input
+        .lines()
+        .skip(2)
+        .take_while(|l| l.trim() != "#########")
+        .collect();
+    let amphipod_rows: Vec<_> = lines
+        .iter()
+        .map(|l| {
+            (3..=9)
+                .step_by(2)
+                .map(|n| match l.get(n..=n) {
+                    Some("A") => Ok(Amphipod::A),
+                    Some("B") => Ok(Amphipod::B),
+                    Some("C") => Ok(Amphipod::C),
+                    Some("D") => Ok(Amphipod::D),
+                    Some(x) => Err(ParseError::new(x, "unknown amphipod")),
+                    None => Err(ParseError::missing(l, "an amphipod in each room")),
+                })
+                .collect::<aoc_core::Result<Vec<_>>>()
+        })
+        .collect::<aoc_core::Result<_>>()?;
+
+    match amphipod_rows.len() {
+        0 => {
+            return Err(ParseError::missing(
+                input,
+                "rows of amphipods in the side rooms",
+            ))
+        }
+        FOLDED_ROOM_SIZE | UNFOLDED_ROOM_SIZE => {}
+        n => {
+            let message = format!(
+                "side rooms are {} deep, expected {} or {}",
+                n, FOLDED_ROOM_SIZE, UNFOLDED_ROOM_SIZE
+            );
+            return Err(ParseError::new(lines[n - 1], message));
+        }
+    }
+
+    Ok([0, 1, 2, 3].map(|room_idx| {
+        amphipod_rows
+            .iter()
+            .rev()
+            .map(|row| row[room_idx])
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMPTY_HALLWAY: [Option<Amphipod>; 11] = [None; 11];
+
+    #[test]
+    fn test_burrow_is_organized() {
+        let tests: HashMap<[Vec<Amphipod>; 4], bool> = HashMap::from([
+            (STARTING_TEST_SIDE_ROOMS.clone(), false),
+            (ORGANIZED_SIDE_ROOMS.clone(), true),
+        ]);
+
+        for (rooms, expected_result) in tests {
+            let burrow = Burrow::new(rooms);
+            assert_eq!(burrow.is_organized(), expected_result);
+        }
+    }
+
+    #[test]
+    fn test_reachable_hallways() {
+        // open hallway
+        for i in 0..=3 {
+            assert_eq!(
+                Burrow::new(STARTING_TEST_SIDE_ROOMS.clone()).reachable_hallways(i),
+                VALID_HALLWAY_IDXS.to_vec()
+            );
+        }
+
+        // one hallway occupied in middle
+        let mut occupied_middle_hallway = EMPTY_HALLWAY;
+        occupied_middle_hallway[5] = Some(Amphipod::B);
+        let burrow_with_occupied_middle_hallway = Burrow {
+            hallways: occupied_middle_hallway,
+            rooms: STARTING_TEST_SIDE_ROOMS.clone(),
+            room_size: 2,
+        };
+
+        for i in 0..=1 {
+            assert_eq!(
+                burrow_with_occupied_middle_hallway.reachable_hallways(i),
+                [0, 1, 3]
+            );
+        }
+        for i in 2..=3 {
+            assert_eq!(
+                burrow_with_occupied_middle_hallway.reachable_hallways(i),
+                [7, 9, 10]
+            );
+        }
+
+        // room has no valid hallways
+        let mut occupied_hallway_around_first_room = EMPTY_HALLWAY;
+        occupied_hallway_around_first_room[1] = Some(Amphipod::A);
+        occupied_hallway_around_first_room[3] = Some(Amphipod::A);
+        let burrow_with_no_first_room_moves = Burrow {
+            hallways: occupied_hallway_around_first_room,
+            rooms: STARTING_TEST_SIDE_ROOMS.clone(),
+            room_size: 2,
+        };
+        assert_eq!(burrow_with_no_first_room_moves.reachable_hallways(0), []);
+    }
+
+    #[test]
+    fn test_generate_moves() {
+        let from_rooms_to_hallways: Vec<Move> = (0..4)
+            .flat_map(|ri| {
+                VALID_HALLWAY_IDXS
+                    .iter()
+                    .map(|hi| Move {
+                        from: Room(ri),
+                        to: Hallway(*hi),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let tests = HashMap::from([(STARTING_TEST_SIDE_ROOMS.clone(), from_rooms_to_hallways)]);
+
+        for (rooms, expected_moves) in tests {
+            let burrow = Burrow::new(rooms);
+            assert_eq!(burrow.moves(), expected_moves);
+        }
+    }
+
+    #[test]
+    fn test_draw_burrow() {
+        let mut burrow = Burrow::new(STARTING_TEST_SIDE_ROOMS.clone());
+        Move {
+            from: Room(2),
+            to: Hallway(3),
+        }
+        .apply(&mut burrow);
+        let frame = BurrowFrame {
+            burrow,
+            energy: 40,
+            moves: 1,
+        };
+        assert_eq!(
+            frame.draw().to_string(),
+            [
+                "#############",
+                "#...B.......#",
+                "###B#C#.#D###",
+                "  #A#D#C#A#  ",
+                "  #########  ",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_part2_unfolded_input() {
+        // already unfolded, so it's solved as it is
+        let input = aoc_core::input::read_day_input(23, "part2_input").unwrap();
+        let rooms = Day23.parse(&input).unwrap();
+        assert_eq!(Day23.part2(&rooms).to_string(), "43117");
+    }
+
+    #[test]
+    fn test_parse_room_depth() {
+        let input =
+            "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n  #A#D#C#A#\n  #########";
+        let e = Day23.parse(input).unwrap_err().locate(input);
+        assert_eq!(e.line(), Some(5));
+        assert_eq!(e.message(), "side rooms are 3 deep, expected 2 or 4");
+    }
+}
@@ -0,0 +1,363 @@
+use aoc_core::{ParseError, Solution};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fmt::Display;
+use tracing::trace;
+
+lazy_static! {
+    static ref REGISTER_INDEXS: HashMap<char, usize> =
+        HashMap::from([('w', 0), ('x', 1), ('y', 2), ('z', 3)]);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operand {
+    Value(isize),
+    Register(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    // Input reads one digit at a time
+    Input(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Equal(usize, Operand),
+}
+
+struct Alu {
+    registers: [isize; 4],
+    program: Vec<Instruction>,
+}
+
+impl Alu {
+    fn new() -> Self {
+        Alu {
+            registers: [0; 4],
+            program: vec![],
+        }
+    }
+
+    fn with_program(mut self, program: Vec<Instruction>) -> Self {
+        self.program = program;
+        self
+    }
+
+    fn clear_registers(&mut self) {
+        self.registers = [0; 4];
+    }
+
+    fn run(&mut self, input_ints: Vec<isize>) -> [isize; 4] {
+        trace!(?input_ints, "running program");
+
+        let mut input_stream = input_ints.into_iter();
+
+        // let input_str = input_int.to_string();
+        // let mut digit_stream = input_str.chars().map(|d| d.to_digit(10).unwrap());
+
+        for instr in self.program.clone().iter().copied() {
+            match instr {
+                Instruction::Input(reg_idx) => {
+                    let next_digit = input_stream.next();
+                    trace!(?next_digit);
+                    self.registers[reg_idx] = next_digit.unwrap();
+                }
+                Instruction::Add(reg_idx, operand) => {
+                    self.op_and_store(reg_idx, operand, |a, b| a + b);
+                }
+                Instruction::Mul(reg_idx, operand) => {
+                    self.op_and_store(reg_idx, operand, |a, b| a * b);
+                }
+                Instruction::Div(reg_idx, operand) => {
+                    self.op_and_store(reg_idx, operand, |a, b| a / b);
+                }
+                Instruction::Mod(reg_idx, operand) => {
+                    self.op_and_store(reg_idx, operand, |a, b| a % b);
+                }
+                Instruction::Equal(reg_idx, operand) => {
+                    self.op_and_store(reg_idx, operand, |a, b| if a == b { 1 } else { 0 });
+                }
+            }
+        }
+
+        let registers_clone = self.registers;
+        self.clear_registers();
+        registers_clone
+    }
+
+    fn op_and_store(
+        &mut self,
+        reg_idx: usize,
+        operand: Operand,
+        op: impl Fn(isize, isize) -> isize,
+    ) {
+        let reg_value = self.registers[reg_idx];
+        let operand_value = match operand {
+            Operand::Value(v) => v,
+            Operand::Register(ri) => self.registers[ri],
+        };
+
+        self.registers[reg_idx] = op(reg_value, operand_value)
+    }
+}
+
+struct Monad {
+    alu: Alu,
+}
+
+impl Monad {
+    fn new(alu: Alu) -> Self {
+        Monad { alu }
+    }
+
+    fn is_valid_model_number(&mut self, n: usize) -> bool {
+        let digits: Vec<isize> = n
+            .to_string()
+            .chars()
+            .map(|d| d.to_digit(10).unwrap() as isize)
+            .collect();
+        let result = self.alu.run(digits);
+        result[3] == 0
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        parse_alu_program(input)
+    }
+
+    fn part1(&self, program: &Self::Input) -> impl Display {
+        let model_num = solve_model_number(program, true);
+        assert!(
+            Monad::new(Alu::new().with_program(program.clone())).is_valid_model_number(model_num)
+        );
+        model_num
+    }
+
+    fn part2(&self, program: &Self::Input) -> impl Display {
+        let model_num = solve_model_number(program, false);
+        assert!(
+            Monad::new(Alu::new().with_program(program.clone())).is_valid_model_number(model_num)
+        );
+        model_num
+    }
+}
+
+const MODEL_NUMBER_DIGITS: usize = 14;
+const BLOCK_LEN: usize = 18;
+
+// See input/strategy.md. Each digit gets an 18 instruction block that only differs in 3 constants:
+// A in `div z A`, B in `add x B`, and C in `add y C`. Blocks with A == 1 push w + C onto z (as a base 26 stack),
+// and blocks with A == 26 have to pop it back off, which links the pushed and popped digits:
+// I[pop] == I[push] + C_push + B_pop
+fn block_constants(block: &[Instruction]) -> Option<(isize, isize, isize)> {
+    match (block.get(4)?, block.get(5)?, block.get(15)?) {
+        (
+            Instruction::Div(3, Operand::Value(a)),
+            Instruction::Add(1, Operand::Value(b)),
+            Instruction::Add(2, Operand::Value(c)),
+        ) => Some((*a, *b, *c)),
+        _ => None,
+    }
+}
+
+fn solve_model_number(program: &[Instruction], largest: bool) -> usize {
+    let mut digits = [0; MODEL_NUMBER_DIGITS];
+    let mut pushed: Vec<(usize, isize)> = vec![];
+    for (i, block) in program.chunks(BLOCK_LEN).enumerate() {
+        let (a, b, c) = block_constants(block).expect("parse checks every block");
+        if a == 1 {
+            pushed.push((i, c));
+        } else {
+            let (j, c_push) = pushed.pop().expect("parse checks every pop has a push");
+            let diff = c_push + b;
+            digits[j] = if largest {
+                9.min(9 - diff)
+            } else {
+                1.max(1 - diff)
+            };
+            digits[i] = digits[j] + diff;
+        }
+    }
+
+    digits
+        .iter()
+        .fold(0, |acc, &d| acc * 10 + usize::try_from(d).unwrap())
+}
+
+// Parses a MONAD program, checking it has the shape solve_model_number relies on
+fn parse_alu_program(program_str: &str) -> aoc_core::Result<Vec<Instruction>> {
+    let program = parse_instructions(program_str)?;
+    let lines: Vec<&str> = program_str.lines().collect();
+    let expected_len = MODEL_NUMBER_DIGITS * BLOCK_LEN;
+    if program.len() < expected_len {
+        let e = format!(
+            "{} blocks of {} instructions, one for each digit",
+            MODEL_NUMBER_DIGITS, BLOCK_LEN
+        );
+        return Err(ParseError::missing(program_str, &e));
+    }
+    if program.len() > expected_len {
+        let e = format!("expected only {} instructions", expected_len);
+        return Err(ParseError::new(lines[expected_len], e));
+    }
+
+    let mut pushed: Vec<isize> = vec![];
+    for (i, block) in program.chunks(BLOCK_LEN).enumerate() {
+        let block_lines = &lines[i * BLOCK_LEN..(i + 1) * BLOCK_LEN];
+        let Some((a, b, c)) = block_constants(block) else {
+            return Err(ParseError::new(
+                block_lines[0],
+                "expected a MONAD digit block",
+            ));
+        };
+        match a {
+            1 => pushed.push(c),
+            26 => {
+                let Some(c_push) = pushed.pop() else {
+                    return Err(ParseError::new(block_lines[4], "nothing left on z to pop"));
+                };
+                // I[pop] == I[push] + C_push + B_pop has to leave both digits from 1 to 9
+                if (c_push + b).abs() > 8 {
+                    let e = "no pair of digits satisfies this block and the one it pops";
+                    return Err(ParseError::new(block_lines[5], e));
+                }
+            }
+            _ => {
+                return Err(ParseError::new(
+                    block_lines[4],
+                    "expected to divide z by 1 or 26",
+                ))
+            }
+        }
+    }
+    if !pushed.is_empty() {
+        return Err(ParseError::missing(
+            program_str,
+            "a block dividing z by 26 for each one dividing it by 1",
+        ));
+    }
+    Ok(program)
+}
+
+fn parse_instructions(program_str: &str) -> aoc_core::Result<Vec<Instruction>> {
+    program_str
+        .lines()
+        .map(|l| {
+            let mut tokens = l.split_whitespace();
+            let instr_name = tokens
+                .next()
+                .ok_or_else(|| ParseError::missing(l, "an instruction"))?;
+            // inp is the only instruction without a second operand
+            let instr_fn: Option<fn(usize, Operand) -> Instruction> = match instr_name {
+                "inp" => None,
+                "add" => Some(Instruction::Add),
+                "mul" => Some(Instruction::Mul),
+                "div" => Some(Instruction::Div),
+                "mod" => Some(Instruction::Mod),
+                "eql" => Some(Instruction::Equal),
+                instr => return Err(ParseError::new(instr, "unexpected instruction")),
+            };
+
+            let first_operand = tokens
+                .next()
+                .ok_or_else(|| ParseError::missing(l, "a register"))?;
+            let first_register = parse_register(first_operand)?;
+            match instr_fn {
+                None => Ok(Instruction::Input(first_register)),
+                Some(instr_fn) => {
+                    let second_operand = tokens
+                        .next()
+                        .ok_or_else(|| ParseError::missing(l, "a second operand"))?;
+                    Ok(instr_fn(
+                        first_register,
+                        parse_second_operand(second_operand)?,
+                    ))
+                }
+            }
+        })
+        .collect()
+}
+
+fn parse_register(register_str: &str) -> aoc_core::Result<usize> {
+    let mut chars = register_str.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => REGISTER_INDEXS.get(&c).copied(),
+        _ => None,
+    }
+    .ok_or_else(|| ParseError::new(register_str, "unknown register"))
+}
+
+fn parse_second_operand(operand_str: &str) -> aoc_core::Result<Operand> {
+    if let Ok(v) = operand_str.parse::<isize>() {
+        Ok(Operand::Value(v))
+    } else {
+        Ok(Operand::Register(parse_register(operand_str)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alu() {
+        let negate_program = "inp x
+mul x -1";
+        let three_times_larger_program = "inp z
+inp x
+mul z 3
+eql z x";
+        let to_binary_program = "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2";
+
+        let tests = HashMap::from([
+            (
+                negate_program,
+                vec![(vec![1], [0, -1, 0, 0]), (vec![4], [0, -4, 0, 0])],
+            ),
+            (
+                three_times_larger_program,
+                vec![
+                    (vec![2, 1], [0, 1, 0, 0]),
+                    (vec![2, 2], [0, 2, 0, 0]),
+                    (vec![2, 4], [0, 4, 0, 0]),
+                    (vec![2, 6], [0, 6, 0, 1]),
+                ],
+            ),
+            (
+                to_binary_program,
+                vec![
+                    (vec![1], [0, 0, 0, 1]),
+                    (vec![2], [0, 0, 1, 0]),
+                    (vec![4], [0, 1, 0, 0]),
+                    (vec![8], [1, 0, 0, 0]),
+                    (vec![7], [0, 1, 1, 1]),
+                    (vec![15], [1, 1, 1, 1]),
+                ],
+            ),
+        ]);
+        for (program_str, ps) in tests {
+            let program = parse_instructions(program_str).unwrap();
+            let mut alu = Alu::new().with_program(program);
+            for (inputs, expected_result) in ps {
+                let result = alu.run(inputs);
+                assert_eq!(result, expected_result);
+            }
+        }
+    }
+}
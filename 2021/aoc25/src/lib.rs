@@ -0,0 +1,201 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::viz::{self, Cell, Color, Frame, Visualize};
+use aoc_core::{ParseError, Part, Solution};
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Cucumber {
+    East,
+    South,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeaFloor {
+    grid: Grid<Option<Cucumber>>,
+}
+
+impl SeaFloor {
+    fn parse(seafloor_str: &str) -> aoc_core::Result<Self> {
+        Ok(SeaFloor {
+            grid: Grid::parse(seafloor_str, |c| match c {
+                "." => Ok(None),
+                ">" => Ok(Some(Cucumber::East)),
+                "v" => Ok(Some(Cucumber::South)),
+                x => Err(ParseError::new(x, "could not parse character")),
+            })?,
+        })
+    }
+
+    fn step(&mut self) -> bool {
+        let moved_east = self.move_herd(Cucumber::East, (0, 1));
+        let moved_south = self.move_herd(Cucumber::South, (1, 0));
+        moved_east || moved_south
+    }
+
+    // Moves every cucumber in a herd at once, wrapping around the edges of the sea floor
+    fn move_herd(&mut self, herd: Cucumber, direction: (isize, isize)) -> bool {
+        let mut changed = false;
+        let mut moved = self.grid.clone();
+
+        for (pos, c) in self.grid.iter() {
+            if c.as_ref() == Some(&herd) {
+                let next = self.grid.wrapping_offset(pos, direction);
+                if self.is_empty(next) {
+                    changed = true;
+                    moved[next] = moved[pos].take();
+                }
+            }
+        }
+
+        self.grid = moved;
+        changed
+    }
+
+    fn is_empty(&self, pos: Pos) -> bool {
+        self.grid[pos].is_none()
+    }
+}
+
+impl Display for SeaFloor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let chars = self.grid.map(|c| match c {
+            None => '.',
+            Some(Cucumber::East) => '>',
+            Some(Cucumber::South) => 'v',
+        });
+        write!(f, "{}", chars)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = SeaFloor;
+
+    fn parse(&self, input: &str) -> aoc_core::Result<Self::Input> {
+        SeaFloor::parse(input)
+    }
+
+    fn part1(&self, initial_sea_floor: &Self::Input) -> impl Display {
+        let mut sea_floor = initial_sea_floor.clone();
+
+        // keep advancing sea floor until it doesn't change
+        let mut counter = 1;
+        while sea_floor.step() {
+            counter += 1;
+        }
+        counter
+    }
+
+    // There's no puzzle for the last part 2, you get it for finishing everything else
+    fn part2(&self, _sea_floor: &Self::Input) -> impl Display {
+        "Merry Christmas!"
+    }
+}
+
+#[derive(Clone)]
+struct SeaFloorFrame {
+    step: usize,
+    sea_floor: SeaFloor,
+}
+
+impl Frame for SeaFloorFrame {
+    fn draw(&self) -> Grid<Cell> {
+        self.sea_floor.grid.map(|c| match c {
+            None => Cell::new('.', Color::Gray),
+            Some(Cucumber::East) => Cell::new('>', Color::Green),
+            Some(Cucumber::South) => Cell::new('v', Color::Cyan),
+        })
+    }
+
+    fn caption(&self) -> String {
+        format!("step {}", self.step)
+    }
+}
+
+// Both parts watch the herds until they stop moving
+impl Visualize for Day25 {
+    fn frames(&self, _part: Part, sea_floor: &Self::Input) -> impl Iterator<Item = impl Frame> {
+        let initial = SeaFloorFrame {
+            step: 0,
+            sea_floor: sea_floor.clone(),
+        };
+        viz::steps(initial, |frame| {
+            frame.step += 1;
+            frame.sea_floor.step()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let mut seafloor = SeaFloor::parse("...>>>>>...").unwrap();
+        seafloor.step();
+        seafloor.step();
+        assert_eq!(seafloor.to_string(), "...>>>.>.>.")
+    }
+
+    #[test]
+    fn test_east_then_south() {
+        let mut seafloor = SeaFloor::parse(
+            "..........
+.>v....v..
+.......>..
+..........",
+        )
+        .unwrap();
+        seafloor.step();
+
+        assert_eq!(
+            seafloor.to_string(),
+            "..........
+.>........
+..v....v>.
+.........."
+        )
+    }
+
+    #[test]
+    fn test_wrap() {
+        let mut seafloor = SeaFloor::parse(
+            "...>...
+.......
+......>
+v.....>
+......>
+.......
+..vvv..",
+        )
+        .unwrap();
+        seafloor.step();
+
+        assert_eq!(
+            seafloor.to_string(),
+            "..vv>..
+.......
+>......
+v.....>
+>......
+.......
+....v.."
+        );
+
+        seafloor.step();
+        seafloor.step();
+        seafloor.step();
+        assert_eq!(
+            seafloor.to_string(),
+            ">......
+..v....
+..>.v..
+.>.v...
+...>...
+.......
+v......"
+        );
+    }
+}